@@ -11,6 +11,10 @@ pub fn spawn_overlay_bridge(
 ) {
     tauri::async_runtime::spawn(async move {
         while let Some(update) = rx.recv().await {
+            if baras_core::context::print_events_enabled() {
+                tracing::trace!(timestamp = ?std::time::SystemTime::now(), update = ?update, "OverlayUpdate");
+            }
+
             match update {
                 OverlayUpdate::MetricsUpdated(metrics) => {
                     // Create entries for all overlay types
@@ -4,18 +4,32 @@
 //!
 //! # Command Categories
 //!
-//! - `overlay` - Overlay show/hide, move mode, status, refresh
-//! - `service` - Log files, tailing, config, session info, profiles
-//! - `timers` - Encounter timer CRUD for the timer editor UI
-//! - `effects` - Effect definition CRUD for the effect editor UI
+//! - `query` - Data explorer queries (breakdowns, raid overview, timeline, histograms)
+//! - `session` - Save/reopen a parsed encounter without re-parsing the log
+//! - `share` - Encode/decode basE91 encounter share codes
+//! - `scrub` - Background parquet health scrubber controls
+//! - `triggers` - Arm `CounterCondition` triggers and list live counter IDs
+//! - `workers` - `BackgroundTasks` registry inspection/restart
+//! - `url` - Cross-platform URL opening
+//!
+//! `workers`' commands are left out of `invoke_handler!`. The only
+//! `BackgroundTasks` the app manages holds the parquet scrubber, whose
+//! factory can't build a second instance (see `spawn_scrub` in `lib.rs`), so
+//! `restart_worker` on it would panic instead of restarting the worker.
 
-mod effects;
-mod overlay;
-mod service;
-mod timers;
+mod query;
+mod scrub;
+mod session;
+mod share;
+mod triggers;
+mod url;
+mod workers;
 
 // Re-export all commands for the invoke_handler
-pub use effects::*;
-pub use overlay::*;
-pub use service::*;
-pub use timers::*;
+pub use query::*;
+pub use scrub::*;
+pub use session::*;
+pub use share::*;
+pub use triggers::*;
+pub use url::*;
+pub use workers::*;
@@ -2,14 +2,18 @@
 //!
 //! Provides SQL-based queries over encounter data using DataFusion.
 
-use baras_core::query::{AbilityBreakdown, BreakdownMode, DataTab, EncounterTimeline, EntityBreakdown, RaidOverviewRow, TimeRange, TimeSeriesPoint};
+use std::time::Instant;
+
+use baras_core::query::{AbilityBreakdown, BreakdownMode, DataTab, EncounterTimeline, EntityBreakdown, HeatmapRow, HistoPoint, RaidOverviewRow, TimeRange, TimeSeriesPoint};
 use tauri::State;
 
+use crate::diagnostics;
 use crate::service::ServiceHandle;
 
 /// Query ability breakdown for an encounter and data tab.
 /// Pass encounter_idx for historical, or None for live encounter.
 #[tauri::command]
+#[tracing::instrument(skip(handle, entity_name, time_range, entity_types), fields(tab = ?tab, encounter_idx, rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
 pub async fn query_breakdown(
     handle: State<'_, ServiceHandle>,
     tab: DataTab,
@@ -20,33 +24,69 @@ pub async fn query_breakdown(
     breakdown_mode: Option<BreakdownMode>,
     duration_secs: Option<f32>,
 ) -> Result<Vec<AbilityBreakdown>, String> {
-    handle.query_breakdown(tab, encounter_idx, entity_name, time_range, entity_types, breakdown_mode, duration_secs).await
+    let start = Instant::now();
+    let result = handle.query_breakdown(tab, encounter_idx, entity_name, time_range, entity_types, breakdown_mode, duration_secs).await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("query_breakdown", elapsed);
+
+    result
 }
 
 /// Query damage/healing breakdown by entity for a data tab.
 #[tauri::command]
+#[tracing::instrument(skip(handle, time_range), fields(tab = ?tab, encounter_idx, rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
 pub async fn query_entity_breakdown(
     handle: State<'_, ServiceHandle>,
     tab: DataTab,
     encounter_idx: Option<u32>,
     time_range: Option<TimeRange>,
 ) -> Result<Vec<EntityBreakdown>, String> {
-    handle.query_entity_breakdown(tab, encounter_idx, time_range).await
+    let start = Instant::now();
+    let result = handle.query_entity_breakdown(tab, encounter_idx, time_range).await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("query_entity_breakdown", elapsed);
+
+    result
 }
 
 /// Query raid overview - aggregated stats per player.
 #[tauri::command]
+#[tracing::instrument(skip(handle, time_range), fields(encounter_idx, rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
 pub async fn query_raid_overview(
     handle: State<'_, ServiceHandle>,
     encounter_idx: Option<u32>,
     time_range: Option<TimeRange>,
     duration_secs: Option<f32>,
 ) -> Result<Vec<RaidOverviewRow>, String> {
-    handle.query_raid_overview(encounter_idx, time_range, duration_secs).await
+    let start = Instant::now();
+    let result = handle.query_raid_overview(encounter_idx, time_range, duration_secs).await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("query_raid_overview", elapsed);
+
+    result
 }
 
 /// Query DPS over time with specified bucket size.
 #[tauri::command]
+#[tracing::instrument(skip(handle, source_name, time_range), fields(encounter_idx, bucket_ms, rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
 pub async fn query_dps_over_time(
     handle: State<'_, ServiceHandle>,
     encounter_idx: Option<u32>,
@@ -54,22 +94,102 @@ pub async fn query_dps_over_time(
     source_name: Option<String>,
     time_range: Option<TimeRange>,
 ) -> Result<Vec<TimeSeriesPoint>, String> {
-    handle.query_dps_over_time(encounter_idx, bucket_ms, source_name, time_range).await
+    let start = Instant::now();
+    let result = handle.query_dps_over_time(encounter_idx, bucket_ms, source_name, time_range).await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("query_dps_over_time", elapsed);
+
+    result
 }
 
 /// List available encounter parquet files.
 #[tauri::command]
+#[tracing::instrument(skip(handle), fields(rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
 pub async fn list_encounter_files(
     handle: State<'_, ServiceHandle>,
 ) -> Result<Vec<u32>, String> {
-    handle.list_encounter_files().await
+    let start = Instant::now();
+    let result = handle.list_encounter_files().await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("list_encounter_files", elapsed);
+
+    result
 }
 
 /// Get encounter timeline with phase segments.
 #[tauri::command]
+#[tracing::instrument(skip(handle), fields(encounter_idx, elapsed_ms = tracing::field::Empty))]
 pub async fn query_encounter_timeline(
     handle: State<'_, ServiceHandle>,
     encounter_idx: Option<u32>,
 ) -> Result<EncounterTimeline, String> {
-    handle.query_encounter_timeline(encounter_idx).await
+    let start = Instant::now();
+    let result = handle.query_encounter_timeline(encounter_idx).await;
+    let elapsed = start.elapsed();
+
+    tracing::Span::current().record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    diagnostics::record_call("query_encounter_timeline", elapsed);
+
+    result
+}
+
+/// Per-hit distribution histogram for one ability, for the ability drill-down panel.
+#[tauri::command]
+#[tracing::instrument(skip(handle, entity_name, ability_name, time_range), fields(tab = ?tab, encounter_idx, rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
+pub async fn query_ability_histogram(
+    handle: State<'_, ServiceHandle>,
+    tab: DataTab,
+    encounter_idx: Option<u32>,
+    entity_name: Option<String>,
+    ability_name: String,
+    time_range: Option<TimeRange>,
+) -> Result<Vec<HistoPoint>, String> {
+    let start = Instant::now();
+    let result = handle.query_ability_histogram(tab, encounter_idx, entity_name, ability_name, time_range).await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("query_ability_histogram", elapsed);
+
+    result
+}
+
+/// Per-entity DPS/HPS/TPS heatmap over time, for the timeline heatmap view.
+#[tauri::command]
+#[tracing::instrument(skip(handle, time_range), fields(tab = ?tab, encounter_idx, columns, rows = tracing::field::Empty, elapsed_ms = tracing::field::Empty))]
+pub async fn query_entity_heatmap(
+    handle: State<'_, ServiceHandle>,
+    tab: DataTab,
+    encounter_idx: Option<u32>,
+    columns: usize,
+    time_range: Option<TimeRange>,
+) -> Result<Vec<HeatmapRow>, String> {
+    let start = Instant::now();
+    let result = handle.query_entity_heatmap(tab, encounter_idx, columns, time_range).await;
+    let elapsed = start.elapsed();
+
+    let span = tracing::Span::current();
+    span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.0);
+    if let Ok(rows) = &result {
+        span.record("rows", rows.len());
+    }
+    diagnostics::record_call("query_entity_heatmap", elapsed);
+
+    result
 }
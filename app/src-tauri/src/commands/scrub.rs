@@ -0,0 +1,39 @@
+//! Parquet scrub commands
+//!
+//! Lets the encounter explorer warn about broken/mismatched parquet files
+//! before a DataFusion query fails on them.
+
+use tauri::State;
+
+use crate::scrub::{FileHealth, ScrubCommand, ScrubHandle};
+
+#[tauri::command]
+pub fn start_scrub(handle: State<'_, ScrubHandle>) -> Result<(), String> {
+    handle.cmd_tx.send(ScrubCommand::Start).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn pause_scrub(handle: State<'_, ScrubHandle>) -> Result<(), String> {
+    handle.cmd_tx.send(ScrubCommand::Pause).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn cancel_scrub(handle: State<'_, ScrubHandle>) -> Result<(), String> {
+    handle.cmd_tx.send(ScrubCommand::Cancel).map_err(|e| e.to_string())
+}
+
+/// Set the "tranquility" knob: the worker sleeps `tranquility *
+/// last_file_scan_duration` between files, so higher values keep a full
+/// scan lower priority.
+#[tauri::command]
+pub fn set_scrub_tranquility(handle: State<'_, ScrubHandle>, n: f64) -> Result<(), String> {
+    handle
+        .cmd_tx
+        .send(ScrubCommand::SetTranquility(n))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn scrub_report(handle: State<'_, ScrubHandle>) -> Vec<FileHealth> {
+    handle.report.snapshot()
+}
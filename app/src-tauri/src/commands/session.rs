@@ -0,0 +1,45 @@
+//! Saved parse session commands
+//!
+//! Lets the data explorer save the currently loaded encounter to disk and
+//! reopen it later without re-parsing the combat log.
+
+use std::path::PathBuf;
+
+use baras_core::context::SavedSession;
+use baras_core::query::DataTab;
+use tauri::State;
+
+use crate::service::ServiceHandle;
+
+/// Snapshot the given encounter's entity/ability breakdowns and raid
+/// overview, and write them to `path` as a [`SavedSession`].
+#[tauri::command]
+pub async fn save_session(
+    handle: State<'_, ServiceHandle>,
+    encounter_idx: Option<u32>,
+    encounter_name: String,
+    path: PathBuf,
+) -> Result<(), String> {
+    let overview = handle.query_raid_overview(encounter_idx, None, None).await?;
+    let entities = handle.query_entity_breakdown(DataTab::Damage, encounter_idx, None).await?;
+    let abilities = handle
+        .query_breakdown(DataTab::Damage, encounter_idx, None, None, None, None, None)
+        .await?;
+
+    let session = SavedSession {
+        schema_version: baras_core::context::CURRENT_SCHEMA_VERSION.to_string(),
+        encounter_name,
+        entities,
+        abilities,
+        overview,
+    };
+
+    session.save(&path)
+}
+
+/// Reopen a previously saved session, migrating it to the current schema if
+/// it was written by an older version of the app.
+#[tauri::command]
+pub fn load_session(path: PathBuf) -> Result<SavedSession, String> {
+    SavedSession::load(&path)
+}
@@ -0,0 +1,43 @@
+//! Share-code commands
+//!
+//! Lets the GUI produce and consume basE91 share codes for an encounter so a
+//! parse can be pasted into Discord/forums without hosting a file.
+
+use baras::share::{decode_share_code, encode_share_code, ShareCode};
+use baras::summary::EntitySummary;
+use baras_core::query::TimeRange;
+use tauri::State;
+
+use crate::service::ServiceHandle;
+
+/// Produce a share code for an encounter's raid overview.
+#[tauri::command]
+pub async fn get_share_code(
+    handle: State<'_, ServiceHandle>,
+    encounter_idx: Option<u32>,
+    duration_secs: Option<f32>,
+) -> Result<String, String> {
+    let rows = handle
+        .query_raid_overview(encounter_idx, None::<TimeRange>, duration_secs)
+        .await?;
+
+    let entities: Vec<EntitySummary> = rows
+        .into_iter()
+        .map(|row| EntitySummary {
+            name: row.name,
+            damage_total: row.damage_total as i64,
+            effective_damage_total: row.effective_damage_total as i64,
+            heal_total: row.healing_total as i64,
+            effective_heal_total: row.effective_healing_total as i64,
+            threat_total: row.threat_total,
+        })
+        .collect();
+
+    encode_share_code(duration_secs.unwrap_or(0.0) as f64, &entities)
+}
+
+/// Decode a pasted share code back into a summary for display.
+#[tauri::command]
+pub fn decode_share(code: String) -> Result<ShareCode, String> {
+    decode_share_code(&code)
+}
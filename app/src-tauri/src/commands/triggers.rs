@@ -0,0 +1,24 @@
+//! Counter/trigger editor commands
+//!
+//! Lets the encounter editor arm a new trigger set and populate its counter
+//! selector, rather than `TriggerEngine::definitions` being unreachable from
+//! the frontend.
+
+use baras_core::context::TriggerDefinition;
+use tauri::State;
+
+use crate::service::ServiceHandle;
+
+/// Replace the armed phase/timer triggers, e.g. after the encounter editor
+/// saves a `CounterCondition`.
+#[tauri::command]
+pub async fn set_triggers(handle: State<'_, ServiceHandle>, definitions: Vec<TriggerDefinition>) -> Result<(), String> {
+    handle.set_triggers(definitions).await
+}
+
+/// Counter IDs currently tracked by the live fight, for populating
+/// `CounterConditionEditor`'s selector.
+#[tauri::command]
+pub async fn counter_ids(handle: State<'_, ServiceHandle>) -> Result<Vec<String>, String> {
+    handle.counter_ids().await
+}
@@ -0,0 +1,49 @@
+//! Background worker introspection commands
+//!
+//! Surfaces the `BackgroundTasks` registry to the UI so a dead worker shows
+//! up as a status instead of the app silently stalling.
+
+use baras_core::context::{BackgroundTasks, WorkerState};
+use tauri::State;
+
+/// Per-worker status for the UI.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WorkerInfo {
+    pub name: String,
+    pub state: String,
+    pub last_error: Option<String>,
+    pub iterations: u64,
+}
+
+fn state_label(state: WorkerState) -> String {
+    match state {
+        WorkerState::Active => "active".to_string(),
+        WorkerState::Idle => "idle".to_string(),
+        WorkerState::Dead => "dead".to_string(),
+    }
+}
+
+/// List every registered worker's name, state, last error, and iteration count.
+#[tauri::command]
+pub fn list_workers(tasks: State<'_, BackgroundTasks>) -> Vec<WorkerInfo> {
+    tasks
+        .list()
+        .into_iter()
+        .map(|status| WorkerInfo {
+            name: status.name,
+            state: state_label(status.state),
+            last_error: status.last_error,
+            iterations: status.iterations,
+        })
+        .collect()
+}
+
+/// Restart a dead (or stuck) worker by name.
+#[tauri::command]
+pub fn restart_worker(tasks: State<'_, BackgroundTasks>, name: String) -> Result<(), String> {
+    if tasks.restart(&name) {
+        Ok(())
+    } else {
+        Err(format!("no worker registered under '{name}'"))
+    }
+}
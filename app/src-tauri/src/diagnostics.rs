@@ -0,0 +1,147 @@
+//! Lightweight in-process runtime diagnostics
+//!
+//! No external profiler: query commands and the overlay render loop report
+//! into a small in-memory collector, and `diagnostics()` reads it back out
+//! for a live "what is the app doing right now" view.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Latency samples retained per command before the oldest is overwritten -
+/// enough for a stable p50/p95 without growing unbounded over a long session.
+const MAX_SAMPLES: usize = 256;
+
+struct CommandRecord {
+    samples_ms: Vec<f64>,
+    next: usize,
+    call_count: u64,
+    last_seen: SystemTime,
+}
+
+impl CommandRecord {
+    fn new() -> Self {
+        Self {
+            samples_ms: Vec::with_capacity(MAX_SAMPLES),
+            next: 0,
+            call_count: 0,
+            last_seen: SystemTime::now(),
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        let ms = elapsed.as_secs_f64() * 1000.0;
+        if self.samples_ms.len() < MAX_SAMPLES {
+            self.samples_ms.push(ms);
+        } else {
+            self.samples_ms[self.next] = ms;
+            self.next = (self.next + 1) % MAX_SAMPLES;
+        }
+        self.call_count += 1;
+        self.last_seen = SystemTime::now();
+    }
+
+    fn percentile(&self, p: f64) -> f64 {
+        if self.samples_ms.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.samples_ms.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let idx = (((sorted.len() - 1) as f64) * p).round() as usize;
+        sorted[idx]
+    }
+}
+
+static COLLECTOR: OnceLock<Mutex<HashMap<&'static str, CommandRecord>>> = OnceLock::new();
+
+fn collector() -> &'static Mutex<HashMap<&'static str, CommandRecord>> {
+    COLLECTOR.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Record one call to `name` taking `elapsed`. Called from each instrumented
+/// query command after it completes.
+pub fn record_call(name: &'static str, elapsed: Duration) {
+    collector()
+        .lock()
+        .unwrap()
+        .entry(name)
+        .or_insert_with(CommandRecord::new)
+        .record(elapsed);
+}
+
+/// Aggregated stats for a single command.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CommandDiagnostics {
+    pub name: String,
+    pub call_count: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub last_seen_unix_ms: u64,
+}
+
+/// Snapshot every command's aggregated stats.
+pub fn snapshot_commands() -> Vec<CommandDiagnostics> {
+    collector()
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(name, record)| CommandDiagnostics {
+            name: (*name).to_string(),
+            call_count: record.call_count,
+            p50_ms: record.percentile(0.50),
+            p95_ms: record.percentile(0.95),
+            last_seen_unix_ms: record
+                .last_seen
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        })
+        .collect()
+}
+
+/// Overlay render-loop health, as last reported by the overlay thread.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+pub struct OverlayDiagnostics {
+    pub fps: f32,
+    pub event_loop_alive: bool,
+}
+
+/// Shared handle the overlay thread updates every frame/second and the
+/// `diagnostics()` command reads from. Cheap to clone (an `Arc` inside).
+#[derive(Clone, Default)]
+pub struct OverlayPulse(Arc<OverlayPulseState>);
+
+#[derive(Default)]
+struct OverlayPulseState {
+    fps_bits: AtomicU32,
+    alive: AtomicBool,
+}
+
+impl OverlayPulse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_alive(&self, alive: bool) {
+        self.0.alive.store(alive, Ordering::Relaxed);
+    }
+
+    pub fn set_fps(&self, fps: f32) {
+        self.0.fps_bits.store(fps.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> OverlayDiagnostics {
+        OverlayDiagnostics {
+            fps: f32::from_bits(self.0.fps_bits.load(Ordering::Relaxed)),
+            event_loop_alive: self.0.alive.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Full payload returned by the `diagnostics()` Tauri command.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Diagnostics {
+    pub commands: Vec<CommandDiagnostics>,
+    pub overlay: OverlayDiagnostics,
+}
@@ -1,39 +1,164 @@
+mod commands;
+mod diagnostics;
+mod overlay;
+mod overlay_layout;
+mod query_pool;
+mod relay;
+mod scrub;
+mod service;
+
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::mpsc::{self, Sender};
 use std::sync::Mutex;
 use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
 use tauri::State;
 
-use baras_overlay::{MeterEntry, MeterOverlay, OverlayConfig};
+use baras_core::context::BackgroundTasks;
+use baras_overlay::overlays::{SessionInfoData, SessionInfoOverlay};
+use baras_overlay::{MeterEntry, MeterOverlay, OverlayConfig, OverlayWindow};
 use baras_overlay::renderer::colors;
 
+use diagnostics::OverlayPulse;
+use overlay::session_info::create_session_info_data;
+use overlay_layout::OverlayLayout;
+use scrub::{ScrubHandle, ScrubReport, ScrubWorker};
+use service::ServiceHandle;
+
 /// Commands sent to the overlay thread
 enum OverlayCommand {
     SetMoveMode(bool),
     UpdateEntries(Vec<MeterEntry>),
+    ResetLayout(OverlayLayout),
     Shutdown,
 }
 
 /// State managing the overlay thread
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct OverlayState {
     tx: Option<Sender<OverlayCommand>>,
     handle: Option<JoinHandle<()>>,
+    live_feed: Option<tauri::async_runtime::JoinHandle<()>>,
     is_running: bool,
     move_mode: bool,
+    pulse: OverlayPulse,
+}
+
+/// Refresh rate for the live overlay feed, stored as `f32::to_bits` so it can
+/// be tuned at runtime via `set_overlay_refresh_hz` without re-spawning the
+/// feed task. Defaults to 4 Hz (250 ms), per the request's example interval.
+static REFRESH_HZ_BITS: AtomicU32 = AtomicU32::new(0);
+
+fn refresh_interval() -> Duration {
+    let bits = REFRESH_HZ_BITS.load(Ordering::Relaxed);
+    let hz = if bits == 0 { 4.0 } else { f32::from_bits(bits) };
+    Duration::from_secs_f32(1.0 / hz)
+}
+
+fn set_refresh_hz(hz: f32) {
+    REFRESH_HZ_BITS.store(hz.to_bits(), Ordering::Relaxed);
+}
+
+/// Coalesces bursty updates so the overlay repaints at most once per
+/// `min_interval`: an update that arrives early is buffered as `pending` and
+/// only sent once the interval has elapsed, rather than dropped.
+struct UpdateThrottle {
+    last_sent: Instant,
+    pending: Option<Vec<MeterEntry>>,
+}
+
+impl UpdateThrottle {
+    fn new() -> Self {
+        Self {
+            last_sent: Instant::now() - refresh_interval(),
+            pending: None,
+        }
+    }
+
+    /// Offer freshly-queried entries. Returns the entries to send now, if the
+    /// interval has elapsed, coalescing away any earlier pending snapshot.
+    fn offer(&mut self, entries: Vec<MeterEntry>) -> Option<Vec<MeterEntry>> {
+        self.pending = Some(entries);
+        self.take_due()
+    }
+
+    /// Emit the latest pending snapshot if `min_interval` has passed since
+    /// the last send.
+    fn take_due(&mut self) -> Option<Vec<MeterEntry>> {
+        if self.pending.is_some() && self.last_sent.elapsed() >= refresh_interval() {
+            self.last_sent = Instant::now();
+            self.pending.take()
+        } else {
+            None
+        }
+    }
 }
 
-/// Spawn the overlay on a separate thread
-fn spawn_overlay() -> (Sender<OverlayCommand>, JoinHandle<()>) {
+/// Poll the live encounter's raid overview and push throttled updates to the
+/// overlay thread via `OverlayCommand::UpdateEntries`.
+fn spawn_live_feed(handle: ServiceHandle, tx: Sender<OverlayCommand>) -> tauri::async_runtime::JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let mut throttle = UpdateThrottle::new();
+        let mut poll = tokio::time::interval(Duration::from_millis(50));
+
+        loop {
+            poll.tick().await;
+
+            let due = match handle.query_raid_overview(None, None, None).await {
+                Ok(rows) => {
+                    let entries = rows
+                        .iter()
+                        .map(|row| MeterEntry {
+                            name: row.name.clone(),
+                            value: row.dps,
+                            max_value: row.dps,
+                            color: colors::dps_bar_fill(),
+                            class_id: None,
+                            icon: None,
+                        })
+                        .collect();
+                    throttle.offer(entries)
+                }
+                Err(_) => throttle.take_due(),
+            };
+
+            if let Some(entries) = due {
+                // Normalize max_value across the snapshot so bars scale
+                // relative to the top parser, not to themselves.
+                let top = entries.iter().map(|e| e.value).fold(0.0_f64, f64::max).max(1.0);
+                let entries = entries
+                    .into_iter()
+                    .map(|mut e| {
+                        e.max_value = top;
+                        e
+                    })
+                    .collect();
+
+                if tx.send(OverlayCommand::UpdateEntries(entries)).is_err() {
+                    break;
+                }
+            }
+        }
+    })
+}
+
+/// Spawn the overlay on a separate thread, restoring `layout` rather than
+/// the previous hardcoded position/size/mode.
+fn spawn_overlay(layout: OverlayLayout, pulse: OverlayPulse) -> (Sender<OverlayCommand>, JoinHandle<()>) {
     let (tx, rx) = mpsc::channel::<OverlayCommand>();
 
     let handle = thread::spawn(move || {
+        let mut layout = layout;
+
         let config = OverlayConfig {
-            x: 50,
-            y: 50,
-            width: 280,
-            height: 200,
+            x: layout.x,
+            y: layout.y,
+            width: layout.width,
+            height: layout.height,
             namespace: "baras-dps".to_string(),
-            click_through: true,
+            click_through: layout.click_through,
+            output: None,
         };
 
         let mut overlay = match MeterOverlay::new(config, "DPS Meter") {
@@ -43,79 +168,256 @@ fn spawn_overlay() -> (Sender<OverlayCommand>, JoinHandle<()>) {
                 return;
             }
         };
+        overlay.window_mut().set_move_mode(layout.move_mode);
 
-        // Set up dummy data
-        let dummy_entries = vec![
-            MeterEntry {
-                name: "Player One".to_string(),
-                value: 15234.0,
-                max_value: 15234.0,
-                color: colors::dps_bar_fill(),
-            },
-            MeterEntry {
-                name: "Player Two".to_string(),
-                value: 12100.0,
-                max_value: 15234.0,
-                color: colors::dps_bar_fill(),
-            },
-            MeterEntry {
-                name: "Player Three".to_string(),
-                value: 9800.0,
-                max_value: 15234.0,
-                color: colors::dps_bar_fill(),
-            },
-            MeterEntry {
-                name: "Player Four".to_string(),
-                value: 7500.0,
-                max_value: 15234.0,
-                color: colors::dps_bar_fill(),
-            },
-        ];
-        overlay.set_entries(dummy_entries);
+        let _render_loop_span = tracing::info_span!("overlay_render_loop").entered();
+        pulse.set_alive(true);
+        let mut frames_this_second: u32 = 0;
+        let mut fps_window_start = Instant::now();
+        // Mirrors the dirty flag each `OverlayPlatform` backend keeps
+        // internally: only repaint when something actually changed instead
+        // of redrawing an unchanged buffer every tick. Starts `true` so the
+        // first frame always paints.
+        let mut needs_render = true;
 
         loop {
             // Check for commands (non-blocking)
             while let Ok(cmd) = rx.try_recv() {
                 match cmd {
                     OverlayCommand::SetMoveMode(enabled) => {
-                        overlay.window_mut().set_click_through(!enabled);
+                        layout.move_mode = enabled;
+                        layout.click_through = !enabled;
+                        overlay.window_mut().set_move_mode(enabled);
+                        overlay.window_mut().set_click_through(layout.click_through);
+                        layout.save();
+                        overlay.window_mut().request_redraw();
+                        needs_render = true;
                     }
                     OverlayCommand::UpdateEntries(entries) => {
                         overlay.set_entries(entries);
+                        overlay.window_mut().request_redraw();
+                        needs_render = true;
+                    }
+                    OverlayCommand::ResetLayout(defaults) => {
+                        layout = defaults;
+                        overlay.window_mut().set_position(layout.x, layout.y);
+                        overlay.window_mut().set_size(layout.width, layout.height);
+                        overlay.window_mut().set_click_through(layout.click_through);
+                        layout.save();
+                        overlay.window_mut().request_redraw();
+                        needs_render = true;
                     }
                     OverlayCommand::Shutdown => {
+                        layout.save();
+                        pulse.set_alive(false);
                         return;
                     }
                 }
             }
 
             // Poll events and render
-            if !overlay.poll_events() {
+            let poll_start = Instant::now();
+            let still_open = overlay.poll_events();
+            let poll_elapsed = poll_start.elapsed();
+            tracing::trace!(poll_ms = poll_elapsed.as_secs_f64() * 1000.0, "overlay event poll");
+            if !still_open {
                 break;
             }
-            overlay.render();
+
+            // The resize-corner drag already tracks a pending size; apply
+            // and persist it once the drag settles on a new value.
+            if let Some((width, height)) = overlay.window_mut().pending_size() {
+                if (width, height) != (layout.width, layout.height) {
+                    layout.width = width;
+                    layout.height = height;
+                    overlay.window_mut().set_size(width, height);
+                    layout.save();
+                    overlay.window_mut().request_redraw();
+                    needs_render = true;
+                }
+            }
+
+            // A live resize/move drag needs a frame every tick to track the
+            // pointer even though no command arrived this iteration.
+            if overlay.window_mut().is_resizing() || layout.move_mode {
+                overlay.window_mut().request_redraw();
+                needs_render = true;
+            }
+
+            if needs_render {
+                overlay.render();
+                frames_this_second += 1;
+                needs_render = false;
+            }
+
+            let window_elapsed = fps_window_start.elapsed();
+            if window_elapsed >= Duration::from_secs(1) {
+                let fps = frames_this_second as f32 / window_elapsed.as_secs_f32();
+                pulse.set_fps(fps);
+                tracing::debug!(frames = frames_this_second, fps, "overlay render loop tick");
+                frames_this_second = 0;
+                fps_window_start = Instant::now();
+            }
 
             // Minimal sleep - just yield to OS scheduler
             // The event loop naturally blocks when waiting for events
             thread::sleep(std::time::Duration::from_millis(1));
         }
+
+        layout.save();
+        pulse.set_alive(false);
+    });
+
+    (tx, handle)
+}
+
+/// Commands sent to the session-info overlay thread.
+enum SessionInfoCommand {
+    UpdateData(SessionInfoData),
+    Shutdown,
+}
+
+/// State managing the session-info overlay thread, mirroring [`OverlayState`]
+/// but for the separate always-on status footer window.
+#[derive(Default)]
+struct SessionInfoState {
+    tx: Option<Sender<SessionInfoCommand>>,
+    handle: Option<JoinHandle<()>>,
+    live_feed: Option<tauri::async_runtime::JoinHandle<()>>,
+    is_running: bool,
+}
+
+/// Poll the service's session summary and push it to the session-info
+/// overlay thread, same shape as `spawn_live_feed`.
+fn spawn_session_info_feed(handle: ServiceHandle, tx: Sender<SessionInfoCommand>) -> tauri::async_runtime::JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let mut poll = tokio::time::interval(Duration::from_millis(500));
+        loop {
+            poll.tick().await;
+            let Ok(summary) = handle.session_summary().await else {
+                continue;
+            };
+            let data = create_session_info_data(&summary);
+            if tx.send(SessionInfoCommand::UpdateData(data)).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// Spawn the session-info overlay on its own thread - a small always-on
+/// status footer, separate from the DPS meter window.
+fn spawn_session_info_overlay() -> (Sender<SessionInfoCommand>, JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel::<SessionInfoCommand>();
+
+    let handle = thread::spawn(move || {
+        let config = OverlayConfig {
+            x: 50,
+            y: 300,
+            width: 260,
+            height: 180,
+            namespace: "baras-session-info".to_string(),
+            click_through: true,
+            output: None,
+        };
+
+        let mut window = match OverlayWindow::new(config) {
+            Ok(w) => w,
+            Err(e) => {
+                eprintln!("Failed to create session info overlay: {}", e);
+                return;
+            }
+        };
+
+        let mut overlay = SessionInfoOverlay::new();
+
+        loop {
+            while let Ok(cmd) = rx.try_recv() {
+                match cmd {
+                    SessionInfoCommand::UpdateData(data) => overlay.set_data(data),
+                    SessionInfoCommand::Shutdown => return,
+                }
+            }
+
+            let events = window.poll_events();
+            if events.iter().any(|e| matches!(e, baras_overlay::InputEvent::CloseRequested)) {
+                break;
+            }
+
+            let width = window.width() as f32;
+            let height = window.height() as f32;
+            window.clear(colors::transparent());
+            window.fill_rounded_rect(0.0, 0.0, width, height, 8.0, colors::overlay_bg());
+            overlay.render(&mut window, 12.0, 12.0, width - 24.0, 14.0);
+            window.commit();
+
+            thread::sleep(std::time::Duration::from_millis(1));
+        }
     });
 
     (tx, handle)
 }
 
 #[tauri::command]
-fn show_overlay(state: State<'_, Mutex<OverlayState>>) -> Result<bool, String> {
+fn show_session_info_overlay(
+    state: State<'_, Mutex<SessionInfoState>>,
+    service: State<'_, ServiceHandle>,
+) -> Result<bool, String> {
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+
+    if state.is_running {
+        return Ok(true);
+    }
+
+    let (tx, handle) = spawn_session_info_overlay();
+    state.live_feed = Some(spawn_session_info_feed(service.inner().clone(), tx.clone()));
+    state.tx = Some(tx);
+    state.handle = Some(handle);
+    state.is_running = true;
+
+    Ok(true)
+}
+
+#[tauri::command]
+fn hide_session_info_overlay(state: State<'_, Mutex<SessionInfoState>>) -> Result<bool, String> {
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+
+    if !state.is_running {
+        return Ok(true);
+    }
+
+    if let Some(live_feed) = state.live_feed.take() {
+        live_feed.abort();
+    }
+
+    if let Some(tx) = state.tx.take() {
+        let _ = tx.send(SessionInfoCommand::Shutdown);
+    }
+
+    if let Some(handle) = state.handle.take() {
+        let _ = handle.join();
+    }
+
+    state.is_running = false;
+
+    Ok(true)
+}
+
+#[tauri::command]
+fn show_overlay(state: State<'_, Mutex<OverlayState>>, service: State<'_, ServiceHandle>) -> Result<bool, String> {
     let mut state = state.lock().map_err(|e| e.to_string())?;
 
     if state.is_running {
         return Ok(true); // Already running
     }
 
-    let (tx, handle) = spawn_overlay();
+    let layout = OverlayLayout::load();
+    let (tx, handle) = spawn_overlay(layout, state.pulse.clone());
+    state.live_feed = Some(spawn_live_feed(service.inner().clone(), tx.clone()));
     state.tx = Some(tx);
     state.handle = Some(handle);
     state.is_running = true;
+    state.move_mode = layout.move_mode;
 
     Ok(true)
 }
@@ -128,6 +430,10 @@ fn hide_overlay(state: State<'_, Mutex<OverlayState>>) -> Result<bool, String> {
         return Ok(true); // Already stopped
     }
 
+    if let Some(live_feed) = state.live_feed.take() {
+        live_feed.abort();
+    }
+
     if let Some(tx) = state.tx.take() {
         let _ = tx.send(OverlayCommand::Shutdown);
     }
@@ -142,6 +448,17 @@ fn hide_overlay(state: State<'_, Mutex<OverlayState>>) -> Result<bool, String> {
     Ok(true)
 }
 
+/// Tune how often the live overlay feed may push updates. Takes effect on
+/// the next poll tick; no need to restart the overlay.
+#[tauri::command]
+fn set_overlay_refresh_hz(hz: f32) -> Result<(), String> {
+    if !hz.is_finite() || hz <= 0.0 {
+        return Err("refresh rate must be a positive, finite number of Hz".to_string());
+    }
+    set_refresh_hz(hz);
+    Ok(())
+}
+
 #[tauri::command]
 fn toggle_move_mode(state: State<'_, Mutex<OverlayState>>) -> Result<bool, String> {
     let mut state = state.lock().map_err(|e| e.to_string())?;
@@ -166,16 +483,109 @@ fn get_overlay_status(state: State<'_, Mutex<OverlayState>>) -> Result<(bool, bo
     Ok((state.is_running, state.move_mode))
 }
 
+/// Restore the overlay's default position, size, and mode, persisting the
+/// reset immediately and applying it live if the overlay is showing.
+#[tauri::command]
+fn reset_overlay_layout(state: State<'_, Mutex<OverlayState>>) -> Result<(), String> {
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+
+    let defaults = OverlayLayout::default();
+    defaults.save();
+    state.move_mode = defaults.move_mode;
+
+    if let Some(tx) = &state.tx {
+        tx.send(OverlayCommand::ResetLayout(defaults)).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Live "what is the app doing right now" snapshot: per-command call counts
+/// and p50/p95 latency, plus the overlay's current FPS and whether its event
+/// loop is still pumping.
+#[tauri::command]
+fn diagnostics(state: State<'_, Mutex<OverlayState>>) -> Result<diagnostics::Diagnostics, String> {
+    let state = state.lock().map_err(|e| e.to_string())?;
+    Ok(diagnostics::Diagnostics {
+        commands: diagnostics::snapshot_commands(),
+        overlay: state.pulse.snapshot(),
+    })
+}
+
+/// Spawn the parquet health scrubber onto a dedicated `BackgroundTasks`
+/// registry and return a handle the `scrub` commands can drive.
+///
+/// `ScrubWorker::new` consumes its command receiver by value, which is in
+/// tension with `BackgroundTasks::spawn`'s `Fn() -> W` factory contract (used
+/// to respawn a worker on `restart_worker`): a `Receiver` can't be recreated
+/// once handed out. The receiver is parked behind an `Option` the factory
+/// `.take()`s, so the first (and only expected) spawn succeeds; a restart
+/// after that would find the option empty and panic rather than silently
+/// construct a worker that can never receive a command again.
+fn spawn_scrub(parquet_dir: std::path::PathBuf) -> (BackgroundTasks, ScrubHandle) {
+    let (cmd_tx, cmd_rx) = mpsc::channel();
+    let report = ScrubReport::default();
+    let cmd_rx = Mutex::new(Some(cmd_rx));
+
+    let tasks = BackgroundTasks::new();
+    tasks.spawn({
+        let report = report.clone();
+        move || {
+            let cmd_rx = cmd_rx.lock().unwrap().take().expect("parquet scrubber cannot be restarted: its command channel is single-use");
+            ScrubWorker::new(parquet_dir.clone(), cmd_rx, report.clone())
+        }
+    });
+
+    (tasks, ScrubHandle { cmd_tx, report })
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let (overlay_tx, _overlay_rx) = tokio::sync::mpsc::channel(32);
+    let (combat_service, service_handle) = service::CombatService::new(overlay_tx);
+    let (scrub_tasks, scrub_handle) = spawn_scrub(service_handle.parquet_dir().as_ref().clone());
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .manage(Mutex::new(OverlayState::default()))
+        .manage(Mutex::new(SessionInfoState::default()))
+        .manage(service_handle)
+        .manage(scrub_tasks)
+        .manage(scrub_handle)
+        .setup(|_app| {
+            tauri::async_runtime::spawn(combat_service.run());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             show_overlay,
             hide_overlay,
             toggle_move_mode,
-            get_overlay_status
+            get_overlay_status,
+            set_overlay_refresh_hz,
+            reset_overlay_layout,
+            show_session_info_overlay,
+            hide_session_info_overlay,
+            diagnostics,
+            commands::query_breakdown,
+            commands::query_entity_breakdown,
+            commands::query_raid_overview,
+            commands::query_dps_over_time,
+            commands::list_encounter_files,
+            commands::query_encounter_timeline,
+            commands::query_ability_histogram,
+            commands::query_entity_heatmap,
+            commands::save_session,
+            commands::load_session,
+            commands::get_share_code,
+            commands::decode_share,
+            commands::open_url,
+            commands::set_triggers,
+            commands::counter_ids,
+            commands::start_scrub,
+            commands::pause_scrub,
+            commands::cancel_scrub,
+            commands::set_scrub_tranquility,
+            commands::scrub_report
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
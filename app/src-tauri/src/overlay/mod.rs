@@ -0,0 +1,6 @@
+//! Overlay data-conversion helpers
+//!
+//! Functions for converting service-side state into the overlay crate's
+//! display types.
+
+pub mod session_info;
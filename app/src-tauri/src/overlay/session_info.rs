@@ -0,0 +1,21 @@
+//! Session info entry creation helpers
+//!
+//! Builds the overlay crate's `SessionInfoData` from the service's view of
+//! the currently tailed file and parsing session.
+
+use baras_overlay::overlays::SessionInfoData;
+
+use crate::service::SessionSummary;
+
+/// Create session info overlay data from the service's session summary.
+pub fn create_session_info_data(summary: &SessionSummary) -> SessionInfoData {
+    SessionInfoData {
+        file_name: summary.file_name.clone(),
+        character_name: summary.character_name.clone(),
+        discipline: summary.discipline.clone(),
+        encounter_name: summary.encounter_name.clone(),
+        encounter_duration_secs: summary.encounter_duration_secs,
+        encounter_count: summary.encounter_count,
+        total_events: summary.total_events,
+    }
+}
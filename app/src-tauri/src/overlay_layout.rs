@@ -0,0 +1,66 @@
+//! Persisted overlay geometry and mode
+//!
+//! `spawn_overlay` used to hardcode position (50,50), size (280x200), and
+//! `click_through`, so every restart (and every `hide_overlay`/`show_overlay`
+//! cycle) reset the user's layout. `OverlayLayout` persists those fields to a
+//! small TOML sidecar next to `config.toml`, the same way `AppConfig` persists
+//! settings, so `show_overlay` can restore where the user left the meter.
+
+use std::path::PathBuf;
+
+use baras_core::context::AppConfig;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverlayLayout {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub move_mode: bool,
+    pub click_through: bool,
+}
+
+impl Default for OverlayLayout {
+    fn default() -> Self {
+        Self {
+            x: 50,
+            y: 50,
+            width: 280,
+            height: 200,
+            move_mode: false,
+            click_through: true,
+        }
+    }
+}
+
+impl OverlayLayout {
+    /// `$XDG_CONFIG_HOME/baras/overlay_layout.toml`, alongside `config.toml`.
+    pub fn path() -> PathBuf {
+        AppConfig::config_path()
+            .parent()
+            .map(|dir| dir.join("overlay_layout.toml"))
+            .unwrap_or_else(|| PathBuf::from("overlay_layout.toml"))
+    }
+
+    /// Load the persisted layout, falling back to defaults if the file is
+    /// missing or fails to parse.
+    pub fn load() -> Self {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|s| toml::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist this layout, creating the config directory if needed.
+    pub fn save(&self) {
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        if let Ok(toml) = toml::to_string_pretty(self) {
+            let _ = std::fs::write(path, toml);
+        }
+    }
+}
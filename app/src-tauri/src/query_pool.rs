@@ -0,0 +1,97 @@
+//! Bounded pool for offloading DataFusion queries
+//!
+//! `query_breakdown`/`query_raid_overview`/etc. are CPU- and IO-bound
+//! (parquet scans, SQL aggregation) and would otherwise block Tauri's async
+//! executor while a large encounter is crunched, freezing other commands
+//! including overlay updates. `QueryPool` routes that work through
+//! `tokio::task::spawn_blocking` behind a small semaphore, so only a bounded
+//! number of queries run at once and excess callers get a typed `Busy`
+//! error instead of piling work onto the runtime.
+
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+
+/// Max number of queries allowed to run concurrently before new callers are
+/// rejected with [`QueryError::Busy`].
+const MAX_INFLIGHT_QUERIES: usize = 4;
+
+/// Typed error returned by query commands, distinguishing backpressure from
+/// a query that actually failed.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QueryError {
+    /// Too many queries are already in flight; the caller should retry.
+    Busy,
+    /// The query itself failed (bad parquet file, SQL error, etc.).
+    Failed { message: String },
+}
+
+impl std::fmt::Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryError::Busy => write!(f, "too many queries in flight, try again shortly"),
+            QueryError::Failed { message } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl From<String> for QueryError {
+    fn from(message: String) -> Self {
+        QueryError::Failed { message }
+    }
+}
+
+impl From<QueryError> for String {
+    /// Tauri commands surface errors as `String`; encode as JSON so the
+    /// frontend can still distinguish `Busy` (retry) from `Failed` (show
+    /// the message) instead of matching on display text.
+    fn from(err: QueryError) -> Self {
+        serde_json::to_string(&err).unwrap_or_else(|_| err.to_string())
+    }
+}
+
+/// Shared handle to the bounded query pool.
+#[derive(Clone)]
+pub struct QueryPool {
+    semaphore: Arc<Semaphore>,
+}
+
+impl Default for QueryPool {
+    fn default() -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(MAX_INFLIGHT_QUERIES)),
+        }
+    }
+}
+
+impl QueryPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `work` on the blocking thread pool, subject to the in-flight
+    /// query limit. Returns `QueryError::Busy` immediately if the pool is
+    /// saturated rather than queuing indefinitely.
+    pub async fn run_blocking<F, T>(&self, work: F) -> Result<T, QueryError>
+    where
+        F: FnOnce() -> Result<T, String> + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = match self.semaphore.clone().try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => return Err(QueryError::Busy),
+        };
+
+        let result = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            work()
+        })
+        .await
+        .map_err(|e| QueryError::Failed {
+            message: format!("query task panicked: {e}"),
+        })?;
+
+        result.map_err(QueryError::from)
+    }
+}
@@ -0,0 +1,165 @@
+//! Network relay for live combat updates
+//!
+//! Exposes the same `OverlayUpdate` stream the in-process overlay consumes
+//! over a plain TCP socket, newline-delimited JSON, so any number of
+//! external clients (browser overlays, OBS/stream widgets, a remote
+//! raid-lead dashboard) can subscribe without embedding them in the Tauri
+//! window. Modeled on a local-dataspace relay: one task owns the listener
+//! and a `broadcast` channel fans out every update to whichever clients are
+//! still connected, while a slow or silent peer is dropped rather than
+//! allowed to back up the feed for everyone else.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+use crate::service::{OverlayUpdate, PlayerMetrics, ServiceCommand};
+
+/// How long a client has to send the shared-secret handshake line before
+/// it's disconnected.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a write to a client may block before it's considered too slow
+/// to keep up with the feed and disconnected.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Compare two byte strings in constant time, so a client guessing the
+/// shared secret can't learn how many leading bytes it got right from how
+/// long the handshake check took (a plain `==` short-circuits on the first
+/// mismatching byte).
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Wire representation of the relay feed, one JSON object per line.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum RelayEvent {
+    /// Sent once, right after a client connects (and completes the
+    /// handshake, if configured).
+    Snapshot { metrics: Vec<PlayerMetrics> },
+    CombatStarted,
+    CombatEnded,
+    MetricsUpdated { metrics: Vec<PlayerMetrics> },
+}
+
+/// Translate a service-internal `OverlayUpdate` to the wire format, dropping
+/// the ones remote clients don't need (the log-file index is local-only).
+fn to_relay_event(update: &OverlayUpdate) -> Option<RelayEvent> {
+    match update {
+        OverlayUpdate::CombatStarted => Some(RelayEvent::CombatStarted),
+        OverlayUpdate::CombatEnded => Some(RelayEvent::CombatEnded),
+        OverlayUpdate::MetricsUpdated(metrics) => Some(RelayEvent::MetricsUpdated { metrics: metrics.clone() }),
+        OverlayUpdate::LogFilesChanged(_) => None,
+    }
+}
+
+/// Spawn the relay listener task, bound to `bind_address`. Each accepted
+/// connection subscribes to `updates` and, if `shared_secret` is set, must
+/// echo it as the first line before it's handed the snapshot and live feed.
+pub fn spawn_relay(
+    bind_address: String,
+    shared_secret: Option<String>,
+    updates: broadcast::Sender<OverlayUpdate>,
+    cmd_tx: mpsc::Sender<ServiceCommand>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(&bind_address).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                tracing::error!(error = %e, address = %bind_address, "failed to start metrics relay listener");
+                return;
+            }
+        };
+        tracing::info!(address = %bind_address, "metrics relay listening");
+
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(pair) => pair,
+                Err(e) => {
+                    tracing::warn!(error = %e, "metrics relay accept failed");
+                    continue;
+                }
+            };
+
+            let rx = updates.subscribe();
+            let cmd_tx = cmd_tx.clone();
+            let secret = shared_secret.clone();
+            tokio::spawn(async move {
+                handle_client(stream, peer, rx, cmd_tx, secret).await;
+            });
+        }
+    })
+}
+
+/// Drive a single connected client: handshake (if required), an initial
+/// snapshot, then the live feed until it disconnects or falls behind.
+async fn handle_client(
+    stream: TcpStream,
+    peer: SocketAddr,
+    mut updates: broadcast::Receiver<OverlayUpdate>,
+    cmd_tx: mpsc::Sender<ServiceCommand>,
+    shared_secret: Option<String>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    if let Some(secret) = &shared_secret {
+        match tokio::time::timeout(HANDSHAKE_TIMEOUT, lines.next_line()).await {
+            Ok(Ok(Some(line))) if constant_time_eq(line.trim().as_bytes(), secret.as_bytes()) => {}
+            _ => {
+                tracing::warn!(%peer, "metrics relay client failed handshake");
+                return;
+            }
+        }
+    }
+
+    let (snapshot_tx, snapshot_rx) = oneshot::channel();
+    if cmd_tx.send(ServiceCommand::GetCurrentMetrics(snapshot_tx)).await.is_err() {
+        return;
+    }
+    let snapshot = snapshot_rx.await.ok().flatten().unwrap_or_default();
+    if write_event(&mut writer, &RelayEvent::Snapshot { metrics: snapshot }).await.is_err() {
+        return;
+    }
+
+    tracing::info!(%peer, "metrics relay client connected");
+
+    loop {
+        match updates.recv().await {
+            Ok(update) => {
+                if let Some(event) = to_relay_event(&update) {
+                    if write_event(&mut writer, &event).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!(%peer, skipped, "metrics relay client too slow, dropping buffered updates");
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+
+    tracing::info!(%peer, "metrics relay client disconnected");
+}
+
+/// Serialize `event` as a line of JSON and write it, bailing out (closing
+/// the connection) if the client hasn't drained its socket within
+/// `WRITE_TIMEOUT`.
+async fn write_event(writer: &mut tokio::net::tcp::OwnedWriteHalf, event: &RelayEvent) -> std::io::Result<()> {
+    let mut payload = serde_json::to_vec(event).unwrap_or_default();
+    payload.push(b'\n');
+
+    match tokio::time::timeout(WRITE_TIMEOUT, writer.write_all(&payload)).await {
+        Ok(result) => result,
+        Err(_) => Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "metrics relay client write timed out")),
+    }
+}
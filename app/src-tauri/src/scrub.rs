@@ -0,0 +1,255 @@
+//! Background parquet health scrubber
+//!
+//! `list_encounter_files` only enumerates indices; nothing validates that
+//! the parquet files behind them are actually readable or match the schema
+//! DataFusion expects. This worker walks every encounter parquet file in
+//! the background, verifies row-group metadata and required columns, and
+//! records per-file health to a sidecar so results survive restarts.
+//!
+//! Runs as a `baras_core::context::Worker` so it shows up in the
+//! `BackgroundTasks` registry like any other long-lived task.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use baras_core::context::{Worker, WorkerState};
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use serde::{Deserialize, Serialize};
+
+/// Columns the DataFusion query layer assumes exist on every encounter
+/// parquet file.
+const REQUIRED_COLUMNS: &[&str] = &["source_name", "target_name", "ability_name", "value", "timestamp_ms"];
+
+/// Commands accepted by the scrub worker's command channel.
+pub enum ScrubCommand {
+    Start,
+    Pause,
+    Cancel,
+    SetTranquility(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunState {
+    Idle,
+    Running,
+    Paused,
+}
+
+/// Health recorded for a single parquet file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileHealth {
+    pub path: PathBuf,
+    pub status: HealthStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HealthStatus {
+    Ok,
+    Corrupt { message: String },
+    SchemaMismatch { missing_columns: Vec<String> },
+}
+
+/// Shared, queryable scrub report backing `scrub_report()`.
+#[derive(Clone, Default)]
+pub struct ScrubReport {
+    inner: Arc<Mutex<HashMap<PathBuf, FileHealth>>>,
+}
+
+impl ScrubReport {
+    pub fn snapshot(&self) -> Vec<FileHealth> {
+        self.inner.lock().unwrap().values().cloned().collect()
+    }
+
+    fn record(&self, health: FileHealth) {
+        self.inner.lock().unwrap().insert(health.path.clone(), health);
+    }
+
+    fn load_sidecar(path: &Path) -> HashMap<PathBuf, FileHealth> {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<Vec<FileHealth>>(&s).ok())
+            .map(|entries| entries.into_iter().map(|e| (e.path.clone(), e)).collect())
+            .unwrap_or_default()
+    }
+
+    fn persist(&self, sidecar: &Path) {
+        let entries: Vec<_> = self.inner.lock().unwrap().values().cloned().collect();
+        if let Ok(json) = serde_json::to_string_pretty(&entries) {
+            let _ = std::fs::write(sidecar, json);
+        }
+    }
+}
+
+/// Low-priority background worker that scrubs parquet files one at a time,
+/// sleeping `tranquility * last_file_scan_duration` between files so a full
+/// scan never saturates disk.
+pub struct ScrubWorker {
+    cmd_rx: Receiver<ScrubCommand>,
+    files: Vec<PathBuf>,
+    cursor: usize,
+    state: RunState,
+    tranquility: f64,
+    last_scan_duration: Duration,
+    sidecar_path: PathBuf,
+    report: ScrubReport,
+}
+
+impl ScrubWorker {
+    /// Create a new scrub worker over `parquet_dir`, restoring any prior
+    /// results from the sidecar file so they survive restarts.
+    pub fn new(parquet_dir: PathBuf, cmd_rx: Receiver<ScrubCommand>, report: ScrubReport) -> Self {
+        let sidecar_path = parquet_dir.join(".scrub_report.json");
+        let restored = ScrubReport::load_sidecar(&sidecar_path);
+        for (_, health) in restored {
+            report.record(health);
+        }
+
+        let files = std::fs::read_dir(&parquet_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("parquet"))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            cmd_rx,
+            files,
+            cursor: 0,
+            state: RunState::Idle,
+            tranquility: 1.0,
+            last_scan_duration: Duration::from_millis(50),
+            sidecar_path,
+            report,
+        }
+    }
+
+    pub fn report_handle(&self) -> ScrubReport {
+        self.report.clone()
+    }
+
+    fn drain_commands(&mut self) {
+        loop {
+            match self.cmd_rx.try_recv() {
+                Ok(ScrubCommand::Start) => self.state = RunState::Running,
+                Ok(ScrubCommand::Pause) => self.state = RunState::Paused,
+                Ok(ScrubCommand::Cancel) => {
+                    self.state = RunState::Idle;
+                    self.cursor = 0;
+                }
+                Ok(ScrubCommand::SetTranquility(n)) => self.tranquility = n.max(0.0),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
+            }
+        }
+    }
+
+    fn scrub_next_file(&mut self) -> bool {
+        if self.cursor >= self.files.len() {
+            return false;
+        }
+
+        let path = self.files[self.cursor].clone();
+        self.cursor += 1;
+
+        let start = Instant::now();
+        let health = scrub_file(&path);
+        self.last_scan_duration = start.elapsed();
+
+        self.report.record(health);
+        self.report.persist(&self.sidecar_path);
+        true
+    }
+}
+
+/// Open a single parquet file and check its row-group metadata and required
+/// columns, returning its recorded health.
+fn scrub_file(path: &Path) -> FileHealth {
+    let file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            return FileHealth {
+                path: path.to_path_buf(),
+                status: HealthStatus::Corrupt { message: e.to_string() },
+            }
+        }
+    };
+
+    let reader = match SerializedFileReader::new(file) {
+        Ok(r) => r,
+        Err(e) => {
+            return FileHealth {
+                path: path.to_path_buf(),
+                status: HealthStatus::Corrupt { message: e.to_string() },
+            }
+        }
+    };
+
+    let metadata = reader.metadata();
+    if metadata.num_row_groups() == 0 {
+        return FileHealth {
+            path: path.to_path_buf(),
+            status: HealthStatus::Corrupt {
+                message: "file has no row groups".to_string(),
+            },
+        };
+    }
+
+    let schema = metadata.file_metadata().schema();
+    let present: Vec<&str> = schema.get_fields().iter().map(|f| f.name()).collect();
+    let missing: Vec<String> = REQUIRED_COLUMNS
+        .iter()
+        .filter(|col| !present.contains(col))
+        .map(|c| c.to_string())
+        .collect();
+
+    if missing.is_empty() {
+        FileHealth {
+            path: path.to_path_buf(),
+            status: HealthStatus::Ok,
+        }
+    } else {
+        FileHealth {
+            path: path.to_path_buf(),
+            status: HealthStatus::SchemaMismatch { missing_columns: missing },
+        }
+    }
+}
+
+impl Worker for ScrubWorker {
+    fn name(&self) -> &str {
+        "parquet-scrubber"
+    }
+
+    fn step(&mut self) -> WorkerState {
+        self.drain_commands();
+
+        match self.state {
+            RunState::Idle | RunState::Paused => WorkerState::Idle,
+            RunState::Running => {
+                if self.scrub_next_file() {
+                    let sleep_for = self.last_scan_duration.mul_f64(self.tranquility);
+                    std::thread::sleep(sleep_for);
+                    WorkerState::Active
+                } else {
+                    self.state = RunState::Idle;
+                    WorkerState::Idle
+                }
+            }
+        }
+    }
+}
+
+/// Handle used by Tauri commands to drive the scrub worker's command
+/// channel without owning the worker itself.
+#[derive(Clone)]
+pub struct ScrubHandle {
+    pub cmd_tx: Sender<ScrubCommand>,
+    pub report: ScrubReport,
+}
@@ -4,11 +4,33 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, oneshot, RwLock};
 
-use baras_core::context::{resolve, AppConfig, DirectoryIndex, ParsingSession};
+use baras_core::context::{resolve, AppConfig, DirectoryEvent, DirectoryIndex, DirectoryWatcher, ParsingSession, TriggerDefinition, TriggerEngine};
+use baras_core::query::{AbilityBreakdown, BreakdownMode, DataTab, EncounterTimeline, EntityBreakdown, HeatmapRow, HistoPoint, RaidOverviewRow, TimeRange, TimeSeriesPoint};
 use baras_core::{GameSignal, Reader, SignalHandler};
 
+use crate::query_pool::QueryPool;
+use crate::relay;
+
+/// Capacity of the broadcast channel fanning `OverlayUpdate`s out to the
+/// metrics relay's connected clients. Generous enough to absorb a burst
+/// without lagging a reasonably-fast client; a slower one just misses the
+/// gap and is told how much it skipped.
+const RELAY_BROADCAST_CAPACITY: usize = 256;
+
+/// Debounce window for coalescing a burst of directory events (e.g. a log
+/// rotating mid-write) into a single index rebuild.
+const DIRECTORY_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// How often armed phase/timer triggers are re-checked against the live
+/// counter registry. Decoupled from the 50ms overlay poll in `lib.rs` -
+/// triggers announce discrete events, not a smoothly-updating rate, so
+/// there's nothing to gain from checking more often than a player could
+/// react to.
+const TRIGGER_TICK_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Updates sent to the overlay system
 #[derive(Debug, Clone)]
 pub enum OverlayUpdate {
@@ -18,6 +40,16 @@ pub enum OverlayUpdate {
     CombatEnded,
     /// Metrics have been updated
     MetricsUpdated(Vec<PlayerMetrics>),
+    /// The log directory changed and the index was rebuilt.
+    LogFilesChanged(Vec<LogFileInfo>),
+    /// An armed phase/timer trigger's condition just became satisfied.
+    TriggerFired {
+        id: String,
+        message: String,
+        /// Set for timed phases so the overlay can run a countdown instead
+        /// of a one-shot callout.
+        countdown_secs: Option<f32>,
+    },
 }
 
 /// Messages sent to the service from Tauri commands
@@ -28,6 +60,34 @@ pub enum ServiceCommand {
     StopTailing,
     /// Refresh the directory index
     RefreshIndex,
+    /// Start watching `config.log_directory` for new/changed log files
+    StartWatching,
+    /// Stop the directory watcher
+    StopWatching,
+    /// Sent internally by the directory watcher task once it has rebuilt
+    /// the index for a batch of filesystem events.
+    IndexUpdated(DirectoryIndex),
+    /// Sent by a newly-connected metrics relay client to request the
+    /// current-encounter snapshot it should open its feed with.
+    GetCurrentMetrics(oneshot::Sender<Option<Vec<PlayerMetrics>>>),
+    /// Replace the armed trigger set, e.g. after the player saves changes in
+    /// the encounter editor.
+    SetTriggers(Vec<TriggerDefinition>),
+    /// Request the counter IDs currently tracked, so `CounterConditionEditor`
+    /// only offers counters that exist in the live fight.
+    GetCounterIds(oneshot::Sender<Vec<String>>),
+    /// Request a snapshot of the currently tailed session, for the
+    /// session-info overlay.
+    GetSessionSummary(oneshot::Sender<SessionSummary>),
+    /// Sent by the trigger tick task; re-evaluates every armed trigger
+    /// against the current counter registry.
+    EvaluateTriggers,
+    /// Sent by the signal handler when combat starts; clears counters and
+    /// armed-trigger state for the new pull.
+    ResetTriggers,
+    /// Sent by the signal handler to add `delta` to a named counter in the
+    /// trigger engine's registry, e.g. a boss-cast tally or a stack count.
+    IncrementCounter(String, i64),
     /// Shutdown the service
     Shutdown,
 }
@@ -36,9 +96,18 @@ pub enum ServiceCommand {
 #[derive(Clone)]
 pub struct ServiceHandle {
     cmd_tx: mpsc::Sender<ServiceCommand>,
+    parquet_dir: Arc<PathBuf>,
+    query_pool: QueryPool,
 }
 
 impl ServiceHandle {
+    /// The directory encounter parquet files are written to, so other
+    /// long-lived tasks (the parquet scrubber) can be pointed at the same
+    /// location without re-deriving it from `AppConfig::load()` themselves.
+    pub fn parquet_dir(&self) -> Arc<PathBuf> {
+        self.parquet_dir.clone()
+    }
+
     pub async fn start_tailing(&self, path: PathBuf) -> Result<(), String> {
         self.cmd_tx
             .send(ServiceCommand::StartTailing(path))
@@ -59,32 +128,264 @@ impl ServiceHandle {
             .await
             .map_err(|e| e.to_string())
     }
+
+    pub async fn start_watching(&self) -> Result<(), String> {
+        self.cmd_tx
+            .send(ServiceCommand::StartWatching)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn stop_watching(&self) -> Result<(), String> {
+        self.cmd_tx
+            .send(ServiceCommand::StopWatching)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Replace the armed phase/timer triggers, e.g. after the encounter
+    /// editor saves a `CounterCondition`.
+    pub async fn set_triggers(&self, definitions: Vec<TriggerDefinition>) -> Result<(), String> {
+        self.cmd_tx
+            .send(ServiceCommand::SetTriggers(definitions))
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Counter IDs currently tracked by the live fight, for populating
+    /// `CounterConditionEditor`'s selector.
+    pub async fn counter_ids(&self) -> Result<Vec<String>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(ServiceCommand::GetCounterIds(tx))
+            .await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())
+    }
+
+    /// Snapshot of the currently tailed session, for the session-info
+    /// overlay.
+    pub async fn session_summary(&self) -> Result<SessionSummary, String> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(ServiceCommand::GetSessionSummary(tx))
+            .await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // DataFusion queries - routed through `query_pool` so parquet scans and
+    // SQL aggregation run on the blocking thread pool instead of the async
+    // executor, with a typed `Busy` error once too many are in flight.
+    // ─────────────────────────────────────────────────────────────────────
+
+    pub async fn query_breakdown(
+        &self,
+        tab: DataTab,
+        encounter_idx: Option<u32>,
+        entity_name: Option<String>,
+        time_range: Option<TimeRange>,
+        entity_types: Option<Vec<String>>,
+        breakdown_mode: Option<BreakdownMode>,
+        duration_secs: Option<f32>,
+    ) -> Result<Vec<AbilityBreakdown>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || {
+                baras_core::query::ability_breakdown(
+                    &parquet_dir,
+                    tab,
+                    encounter_idx,
+                    entity_name.as_deref(),
+                    time_range.as_ref(),
+                    entity_types.as_deref(),
+                    breakdown_mode,
+                    duration_secs,
+                )
+                .map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(String::from)
+    }
+
+    pub async fn query_entity_breakdown(
+        &self,
+        tab: DataTab,
+        encounter_idx: Option<u32>,
+        time_range: Option<TimeRange>,
+    ) -> Result<Vec<EntityBreakdown>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || {
+                baras_core::query::entity_breakdown(&parquet_dir, tab, encounter_idx, time_range.as_ref())
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(String::from)
+    }
+
+    pub async fn query_raid_overview(
+        &self,
+        encounter_idx: Option<u32>,
+        time_range: Option<TimeRange>,
+        duration_secs: Option<f32>,
+    ) -> Result<Vec<RaidOverviewRow>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || {
+                baras_core::query::raid_overview(&parquet_dir, encounter_idx, time_range.as_ref(), duration_secs)
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(String::from)
+    }
+
+    pub async fn query_dps_over_time(
+        &self,
+        encounter_idx: Option<u32>,
+        bucket_ms: i64,
+        source_name: Option<String>,
+        time_range: Option<TimeRange>,
+    ) -> Result<Vec<TimeSeriesPoint>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || {
+                baras_core::query::dps_over_time(&parquet_dir, encounter_idx, bucket_ms, source_name.as_deref(), time_range.as_ref())
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(String::from)
+    }
+
+    pub async fn list_encounter_files(&self) -> Result<Vec<u32>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || baras_core::query::list_encounter_indices(&parquet_dir).map_err(|e| e.to_string()))
+            .await
+            .map_err(String::from)
+    }
+
+    pub async fn query_encounter_timeline(&self, encounter_idx: Option<u32>) -> Result<EncounterTimeline, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || baras_core::query::encounter_timeline(&parquet_dir, encounter_idx).map_err(|e| e.to_string()))
+            .await
+            .map_err(String::from)
+    }
+
+    /// Per-hit damage/healing distribution for one ability, bucketed with the
+    /// Freedman-Diaconis rule (falling back to Sturges' formula when the IQR
+    /// is 0), so the drill-down panel can render a histogram instead of a mean.
+    pub async fn query_ability_histogram(
+        &self,
+        tab: DataTab,
+        encounter_idx: Option<u32>,
+        entity_name: Option<String>,
+        ability_name: String,
+        time_range: Option<TimeRange>,
+    ) -> Result<Vec<HistoPoint>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || {
+                baras_core::query::ability_histogram(&parquet_dir, tab, encounter_idx, entity_name.as_deref(), &ability_name, time_range.as_ref())
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(String::from)
+    }
+
+    /// Per-entity, per-time-bucket rate (DPS/HPS/TPS depending on `tab`),
+    /// normalized 0-1 across all cells for the heatmap view. `columns`
+    /// partitions the encounter (or `time_range`, if set) into that many
+    /// equal-width buckets.
+    pub async fn query_entity_heatmap(
+        &self,
+        tab: DataTab,
+        encounter_idx: Option<u32>,
+        columns: usize,
+        time_range: Option<TimeRange>,
+    ) -> Result<Vec<HeatmapRow>, String> {
+        let parquet_dir = self.parquet_dir.clone();
+        self.query_pool
+            .run_blocking(move || {
+                baras_core::query::entity_heatmap(&parquet_dir, tab, encounter_idx, columns, time_range.as_ref())
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(String::from)
+    }
+}
+
+/// Spawn the ticker that drives `ServiceCommand::EvaluateTriggers` on
+/// `TRIGGER_TICK_INTERVAL`, feeding it back through `cmd_tx` the same way
+/// the directory watcher feeds back `IndexUpdated`.
+fn spawn_trigger_tick(cmd_tx: mpsc::Sender<ServiceCommand>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TRIGGER_TICK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if cmd_tx.send(ServiceCommand::EvaluateTriggers).await.is_err() {
+                break;
+            }
+        }
+    })
 }
 
 /// Signal handler that forwards game signals to the overlay system
 struct OverlaySignalHandler {
     tx: mpsc::Sender<OverlayUpdate>,
+    relay_tx: broadcast::Sender<OverlayUpdate>,
+    // Lets this handler feed counter updates back into the service's
+    // `TriggerEngine`, which lives on the `CombatService` side of `cmd_rx`
+    // rather than here.
+    self_tx: mpsc::Sender<ServiceCommand>,
 }
 
 impl OverlaySignalHandler {
-    fn new(tx: mpsc::Sender<OverlayUpdate>) -> Self {
-        Self { tx }
+    fn new(tx: mpsc::Sender<OverlayUpdate>, relay_tx: broadcast::Sender<OverlayUpdate>, self_tx: mpsc::Sender<ServiceCommand>) -> Self {
+        Self { tx, relay_tx, self_tx }
     }
 }
 
 impl SignalHandler for OverlaySignalHandler {
     fn handle_signal(&mut self, signal: &GameSignal) {
-        // Convert game signals to overlay updates
+        // Convert game signals to overlay updates. Any signal that should
+        // move a counter does so via `self_tx` rather than touching the
+        // trigger engine directly - it lives on the `CombatService` side of
+        // `cmd_rx`, not here.
         let update = match signal {
             GameSignal::CombatStarted { .. } => Some(OverlayUpdate::CombatStarted),
             GameSignal::CombatEnded { .. } => Some(OverlayUpdate::CombatEnded),
-            // TODO: Add more signal -> update mappings as needed
+            // TODO: feed ability casts / stack gains / add spawns into the
+            // trigger engine's counter registry via `self_tx` once this
+            // crate's `GameSignal` grows variants for them - today
+            // `CombatStarted`/`CombatEnded` are the only ones ever
+            // constructed, so there's nothing upstream to match on yet.
             _ => None,
         };
 
         if let Some(update) = update {
+            // A new pull starts every counter and armed trigger fresh; the
+            // "pulls" counter itself must be bumped *after* that reset is
+            // processed, or the increment below would just be wiped by it.
+            if matches!(update, OverlayUpdate::CombatStarted) {
+                let _ = self.self_tx.try_send(ServiceCommand::ResetTriggers);
+                let _ = self.self_tx.try_send(ServiceCommand::IncrementCounter("pulls".to_string(), 1));
+            }
+
+            // A fight that reaches its end condition counts toward
+            // "encounters" whether it was a kill or a wipe; distinct from
+            // "pulls" so a `CounterCondition` can tell "pulled N times" from
+            // "completed N times" apart.
+            if matches!(update, OverlayUpdate::CombatEnded) {
+                let _ = self.self_tx.try_send(ServiceCommand::IncrementCounter("encounters".to_string(), 1));
+            }
+
             // Non-blocking send - drop if channel is full
-            let _ = self.tx.try_send(update);
+            let _ = self.tx.try_send(update.clone());
+            // Ignored if the metrics relay has no connected clients.
+            let _ = self.relay_tx.send(update);
         }
     }
 }
@@ -103,11 +404,32 @@ pub struct CombatService {
     // Channel to send updates to overlays
     overlay_tx: mpsc::Sender<OverlayUpdate>,
 
+    // Fans out the same updates to the metrics relay's connected clients;
+    // a broadcast channel rather than mpsc since there may be zero or many
+    // subscribers and none of them should block the others.
+    relay_tx: broadcast::Sender<OverlayUpdate>,
+
     // Command receiver
     cmd_rx: mpsc::Receiver<ServiceCommand>,
 
+    // Lets background tasks (the directory watcher, the metrics relay) feed
+    // events back into `cmd_rx` as if they were an external command.
+    self_tx: mpsc::Sender<ServiceCommand>,
+
     // Handle for cancelling the tail task
     tail_handle: Option<tokio::task::JoinHandle<()>>,
+
+    // Handle for cancelling the directory watcher task
+    watch_handle: Option<tokio::task::JoinHandle<()>>,
+
+    // Handle for cancelling the metrics relay listener task
+    relay_handle: Option<tokio::task::JoinHandle<()>>,
+
+    // Counter registry and armed phase/timer triggers
+    trigger_engine: TriggerEngine,
+
+    // Handle for cancelling the trigger re-evaluation tick task
+    trigger_tick_handle: tokio::task::JoinHandle<()>,
 }
 
 impl CombatService {
@@ -118,17 +440,40 @@ impl CombatService {
         let config = AppConfig::load();
         let directory_index = DirectoryIndex::build_index(&PathBuf::from(&config.log_directory))
             .unwrap_or_default();
+        let parquet_dir = Arc::new(PathBuf::from(&config.log_directory).join("parquet"));
+
+        let (relay_tx, _relay_rx) = broadcast::channel(RELAY_BROADCAST_CAPACITY);
+        let relay_handle = config.relay.enabled.then(|| {
+            relay::spawn_relay(
+                config.relay.bind_address.clone(),
+                config.relay.shared_secret.clone(),
+                relay_tx.clone(),
+                cmd_tx.clone(),
+            )
+        });
+
+        let trigger_tick_handle = spawn_trigger_tick(cmd_tx.clone());
 
         let service = Self {
             config,
             directory_index,
             session: None,
             overlay_tx,
+            relay_tx,
             cmd_rx,
+            self_tx: cmd_tx.clone(),
             tail_handle: None,
+            watch_handle: None,
+            relay_handle,
+            trigger_engine: TriggerEngine::new(),
+            trigger_tick_handle,
         };
 
-        let handle = ServiceHandle { cmd_tx };
+        let handle = ServiceHandle {
+            cmd_tx,
+            parquet_dir,
+            query_pool: QueryPool::new(),
+        };
 
         (service, handle)
     }
@@ -146,7 +491,40 @@ impl CombatService {
                 ServiceCommand::RefreshIndex => {
                     self.refresh_index();
                 }
+                ServiceCommand::StartWatching => {
+                    self.start_watching();
+                }
+                ServiceCommand::StopWatching => {
+                    self.stop_watching();
+                }
+                ServiceCommand::IndexUpdated(index) => {
+                    self.apply_index_update(index).await;
+                }
+                ServiceCommand::GetCurrentMetrics(reply) => {
+                    let _ = reply.send(self.current_metrics().await);
+                }
+                ServiceCommand::SetTriggers(definitions) => {
+                    self.trigger_engine.set_definitions(definitions);
+                }
+                ServiceCommand::GetCounterIds(reply) => {
+                    let _ = reply.send(self.trigger_engine.counters().ids());
+                }
+                ServiceCommand::GetSessionSummary(reply) => {
+                    let _ = reply.send(self.session_summary().await);
+                }
+                ServiceCommand::EvaluateTriggers => {
+                    self.evaluate_triggers();
+                }
+                ServiceCommand::ResetTriggers => {
+                    self.trigger_engine.reset();
+                }
+                ServiceCommand::IncrementCounter(counter_id, delta) => {
+                    self.trigger_engine.counters_mut().increment(&counter_id, delta);
+                }
                 ServiceCommand::Shutdown => {
+                    self.stop_watching();
+                    self.stop_relay();
+                    self.trigger_tick_handle.abort();
                     self.stop_tailing().await;
                     break;
                 }
@@ -163,7 +541,7 @@ impl CombatService {
         let mut session = ParsingSession::new(path.clone());
 
         // Add signal handler for overlay updates
-        let handler = OverlaySignalHandler::new(self.overlay_tx.clone());
+        let handler = OverlaySignalHandler::new(self.overlay_tx.clone(), self.relay_tx.clone(), self.self_tx.clone());
         session.add_signal_handler(Box::new(handler));
 
         let session = Arc::new(RwLock::new(session));
@@ -196,6 +574,103 @@ impl CombatService {
         }
     }
 
+    /// Start watching `config.log_directory`, feeding rebuilt indexes back
+    /// through `cmd_rx` as `ServiceCommand::IndexUpdated`.
+    fn start_watching(&mut self) {
+        self.stop_watching();
+
+        let dir = PathBuf::from(&self.config.log_directory);
+        let cmd_tx = self.self_tx.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut watcher = match DirectoryWatcher::new(&dir) {
+                Ok(watcher) => watcher,
+                Err(e) => {
+                    tracing::error!(error = %e, path = %dir.display(), "failed to start directory watcher");
+                    return;
+                }
+            };
+
+            while let Some(event) = watcher.next_event().await {
+                match event {
+                    DirectoryEvent::NewFile(_) | DirectoryEvent::FileModified(_) | DirectoryEvent::FileRemoved(_) => {}
+                    DirectoryEvent::Error(e) => {
+                        tracing::warn!(error = %e, "directory watcher error");
+                        continue;
+                    }
+                    _ => continue,
+                }
+
+                // Coalesce a burst of further events (e.g. a log rotating)
+                // within the debounce window before rebuilding, so one burst
+                // triggers one rebuild.
+                loop {
+                    match tokio::time::timeout(DIRECTORY_DEBOUNCE, watcher.next_event()).await {
+                        Ok(Some(_)) => continue,
+                        Ok(None) | Err(_) => break,
+                    }
+                }
+
+                match DirectoryIndex::build_index(&dir) {
+                    Ok(index) => {
+                        if cmd_tx.send(ServiceCommand::IndexUpdated(index)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => tracing::warn!(error = %e, path = %dir.display(), "failed to rebuild directory index"),
+                }
+            }
+        });
+
+        self.watch_handle = Some(handle);
+    }
+
+    /// Stop the directory watcher task, if running.
+    fn stop_watching(&mut self) {
+        if let Some(handle) = self.watch_handle.take() {
+            handle.abort();
+        }
+    }
+
+    /// Stop the metrics relay listener task, if running.
+    fn stop_relay(&mut self) {
+        if let Some(handle) = self.relay_handle.take() {
+            handle.abort();
+        }
+    }
+
+    /// Re-check every armed trigger, broadcasting an `OverlayUpdate` for
+    /// each one that just transitioned from unsatisfied to satisfied.
+    fn evaluate_triggers(&mut self) {
+        for fired in self.trigger_engine.tick() {
+            let update = OverlayUpdate::TriggerFired {
+                id: fired.id,
+                message: fired.message,
+                countdown_secs: fired.countdown_secs,
+            };
+            let _ = self.overlay_tx.try_send(update.clone());
+            let _ = self.relay_tx.send(update);
+        }
+    }
+
+    /// Swap in a freshly rebuilt index, notify the overlay, and — if
+    /// `config.auto_tail` is set and nothing is currently tailing — start
+    /// tailing the newest non-empty log automatically.
+    async fn apply_index_update(&mut self, index: DirectoryIndex) {
+        self.directory_index = index;
+
+        let files = self.log_files();
+        let _ = self.overlay_tx.try_send(OverlayUpdate::LogFilesChanged(files));
+
+        if self.config.auto_tail && !self.is_tailing() {
+            if let Some(newest) = self.directory_index.newest_file() {
+                if !newest.is_empty {
+                    self.start_tailing(newest.path.clone()).await;
+                }
+            }
+        }
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Query methods for Tauri commands
     // ─────────────────────────────────────────────────────────────────────────────
@@ -205,10 +680,13 @@ impl CombatService {
         &self.config
     }
 
-    /// Update the configuration
-    pub fn set_config(&mut self, config: AppConfig) {
+    /// Update the configuration and persist it atomically, stamped at the
+    /// current schema version.
+    pub fn set_config(&mut self, mut config: AppConfig) -> Result<(), String> {
+        config.version = baras_core::context::CURRENT_CONFIG_VERSION;
+        config.persist()?;
         self.config = config;
-        // TODO: Persist to disk with confy
+        Ok(())
     }
 
     /// Get log file entries for the UI
@@ -254,6 +732,45 @@ impl CombatService {
     pub fn is_tailing(&self) -> bool {
         self.session.is_some()
     }
+
+    /// Summarize the currently tailed session for the session-info overlay.
+    pub async fn session_summary(&self) -> SessionSummary {
+        let Some(session) = self.session.as_ref() else {
+            return SessionSummary::default();
+        };
+        let session = session.read().await;
+
+        let encounter = session
+            .session_cache
+            .as_ref()
+            .and_then(|cache| cache.current_encounter());
+
+        SessionSummary {
+            file_name: session
+                .log_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            character_name: session.character_name.clone(),
+            discipline: session.discipline.clone(),
+            encounter_name: encounter.map(|e| e.name().to_string()),
+            encounter_duration_secs: encounter.map(|e| e.duration_secs()).unwrap_or(0.0),
+            encounter_count: session.encounter_count() as u32,
+            total_events: session.total_events() as u64,
+        }
+    }
+}
+
+/// Snapshot of the tailed session for the session-info overlay.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct SessionSummary {
+    pub file_name: String,
+    pub character_name: Option<String>,
+    pub discipline: Option<String>,
+    pub encounter_name: Option<String>,
+    pub encounter_duration_secs: f64,
+    pub encounter_count: u32,
+    pub total_events: u64,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
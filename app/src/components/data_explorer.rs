@@ -4,15 +4,582 @@
 //! Uses DataFusion SQL queries over parquet files for historical data.
 
 use dioxus::prelude::*;
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::spawn_local as spawn;
 
-use crate::api::{self, AbilityBreakdown, BreakdownMode, DataTab, EncounterTimeline, EntityBreakdown, RaidOverviewRow, TimeRange};
+use crate::api::{self, AbilityBreakdown, BreakdownMode, DataTab, EncounterTimeline, EntityBreakdown, HeatmapRow, HistoPoint, RaidOverviewRow, TimeRange};
 use crate::components::history_panel::EncounterSummary;
 use crate::components::phase_timeline::PhaseTimelineFilter;
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Overview table: configurable columns + multi-key sort
+// ─────────────────────────────────────────────────────────────────────────────
+
+const OVERVIEW_COLUMNS_STORAGE_KEY: &str = "baras.overview_columns";
+const OVERVIEW_SORT_STORAGE_KEY: &str = "baras.overview_sort";
+
+/// A metric column in the raid overview table. `Name` is handled separately
+/// since it's always shown and pinned first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+enum OverviewColumn {
+    DamageTotal,
+    Dps,
+    ThreatTotal,
+    Tps,
+    DamageTakenTotal,
+    Dtps,
+    Aps,
+    HealingTotal,
+    Hps,
+    HealingPct,
+    Ehps,
+}
+
+impl OverviewColumn {
+    const ALL: [OverviewColumn; 11] = [
+        OverviewColumn::DamageTotal,
+        OverviewColumn::Dps,
+        OverviewColumn::ThreatTotal,
+        OverviewColumn::Tps,
+        OverviewColumn::DamageTakenTotal,
+        OverviewColumn::Dtps,
+        OverviewColumn::Aps,
+        OverviewColumn::HealingTotal,
+        OverviewColumn::Hps,
+        OverviewColumn::HealingPct,
+        OverviewColumn::Ehps,
+    ];
+
+    /// Short label used in the table header, matching the original fixed layout.
+    fn header_label(&self) -> &'static str {
+        match self {
+            OverviewColumn::DamageTotal => "Dmg Total",
+            OverviewColumn::Dps => "DPS",
+            OverviewColumn::ThreatTotal => "Threat Total",
+            OverviewColumn::Tps => "TPS",
+            OverviewColumn::DamageTakenTotal => "Taken Total",
+            OverviewColumn::Dtps => "DTPS",
+            OverviewColumn::Aps => "APS",
+            OverviewColumn::HealingTotal => "Heal Total",
+            OverviewColumn::Hps => "HPS",
+            OverviewColumn::HealingPct => "%",
+            OverviewColumn::Ehps => "EHPS",
+        }
+    }
+
+    /// Longer label used in the column picker, where "Dmg Total" alone is ambiguous.
+    fn picker_label(&self) -> &'static str {
+        match self {
+            OverviewColumn::DamageTotal => "Damage Total",
+            OverviewColumn::Dps => "Damage Per Second",
+            OverviewColumn::ThreatTotal => "Threat Total",
+            OverviewColumn::Tps => "Threat Per Second",
+            OverviewColumn::DamageTakenTotal => "Damage Taken Total",
+            OverviewColumn::Dtps => "Damage Taken Per Second",
+            OverviewColumn::Aps => "Absorbed Per Second",
+            OverviewColumn::HealingTotal => "Healing Total",
+            OverviewColumn::Hps => "Healing Per Second",
+            OverviewColumn::HealingPct => "Healing % of Raid",
+            OverviewColumn::Ehps => "Effective Healing Per Second",
+        }
+    }
+
+    fn css_class(&self) -> &'static str {
+        match self {
+            OverviewColumn::DamageTotal | OverviewColumn::Dps => "num dmg",
+            OverviewColumn::ThreatTotal | OverviewColumn::Tps => "num threat",
+            OverviewColumn::DamageTakenTotal | OverviewColumn::Dtps | OverviewColumn::Aps => "num taken",
+            OverviewColumn::HealingTotal | OverviewColumn::Hps | OverviewColumn::HealingPct | OverviewColumn::Ehps => "num heal",
+        }
+    }
+
+    fn value(&self, row: &RaidOverviewRow) -> f64 {
+        match self {
+            OverviewColumn::DamageTotal => row.damage_total,
+            OverviewColumn::Dps => row.dps,
+            OverviewColumn::ThreatTotal => row.threat_total,
+            OverviewColumn::Tps => row.tps,
+            OverviewColumn::DamageTakenTotal => row.damage_taken_total,
+            OverviewColumn::Dtps => row.dtps,
+            OverviewColumn::Aps => row.aps,
+            OverviewColumn::HealingTotal => row.healing_total,
+            OverviewColumn::Hps => row.hps,
+            OverviewColumn::HealingPct => row.healing_pct,
+            OverviewColumn::Ehps => row.ehps,
+        }
+    }
+
+    fn format(&self, row: &RaidOverviewRow) -> String {
+        match self {
+            OverviewColumn::HealingPct => format_pct(self.value(row)),
+            _ => format_number(self.value(row)),
+        }
+    }
+}
+
+fn default_overview_columns() -> Vec<OverviewColumn> {
+    OverviewColumn::ALL.to_vec()
+}
+
+/// What a sort key targets: the pinned name column, or a metric column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+enum SortTarget {
+    Name,
+    Metric(OverviewColumn),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+enum SortDir {
+    Asc,
+    Desc,
+}
+
+impl SortDir {
+    /// Cycle ascending -> descending -> none (`None` means "drop this key").
+    fn next(self) -> Option<SortDir> {
+        match self {
+            SortDir::Asc => Some(SortDir::Desc),
+            SortDir::Desc => None,
+        }
+    }
+
+    fn arrow(self) -> &'static str {
+        match self {
+            SortDir::Asc => "▲",
+            SortDir::Desc => "▼",
+        }
+    }
+}
+
+type SortKey = (SortTarget, SortDir);
+
+/// Stable multi-key sort: the first key is primary, later keys only break
+/// ties left by earlier ones (e.g. sort by DPS, then by Total).
+fn sort_rows(rows: &mut [RaidOverviewRow], keys: &[SortKey]) {
+    rows.sort_by(|a, b| {
+        for (target, dir) in keys {
+            let ord = match target {
+                SortTarget::Name => a.name.cmp(&b.name),
+                SortTarget::Metric(col) => col.value(a).partial_cmp(&col.value(b)).unwrap_or(Ordering::Equal),
+            };
+            let ord = if *dir == SortDir::Desc { ord.reverse() } else { ord };
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+        Ordering::Equal
+    });
+}
+
+/// Click handler for a sortable header: cycles the key's direction
+/// ascending -> descending -> removed, or appends it as a new tie-breaker.
+/// Generic over the sort target so the ability/entity tables can reuse it.
+fn toggle_sort_key<T: Copy + PartialEq>(keys: &mut Vec<(T, SortDir)>, target: T) {
+    if let Some(pos) = keys.iter().position(|(t, _)| *t == target) {
+        match keys[pos].1.next() {
+            Some(next_dir) => keys[pos].1 = next_dir,
+            None => {
+                keys.remove(pos);
+            }
+        }
+    } else {
+        keys.push((target, SortDir::Asc));
+    }
+}
+
+fn sort_indicator<T: Copy + PartialEq>(target: T, keys: &[(T, SortDir)]) -> Option<String> {
+    let pos = keys.iter().position(|(t, _)| *t == target)?;
+    let (_, dir) = keys[pos];
+    if keys.len() > 1 {
+        Some(format!("{}{}", dir.arrow(), pos + 1))
+    } else {
+        Some(dir.arrow().to_string())
+    }
+}
+
+fn load_from_storage<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
+    let storage = web_sys::window()?.local_storage().ok()??;
+    let raw = storage.get_item(key).ok()??;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_to_storage<T: serde::Serialize>(key: &str, value: &T) {
+    let Some(window) = web_sys::window() else { return };
+    let Ok(Some(storage)) = window.local_storage() else { return };
+    if let Ok(json) = serde_json::to_string(value) {
+        let _ = storage.set_item(key, &json);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Ability table: sortable columns + group-by subtotals
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AbilityColumn {
+    Total,
+    Percent,
+    Rate,
+    Hits,
+    Avg,
+    Crit,
+}
+
+impl AbilityColumn {
+    const ALL: [AbilityColumn; 6] = [
+        AbilityColumn::Total,
+        AbilityColumn::Percent,
+        AbilityColumn::Rate,
+        AbilityColumn::Hits,
+        AbilityColumn::Avg,
+        AbilityColumn::Crit,
+    ];
+
+    /// `rate_label` is "DPS"/"HPS"/etc, threaded through from the active tab.
+    fn header_label(&self, rate_label: &str) -> String {
+        match self {
+            AbilityColumn::Total => "Total".to_string(),
+            AbilityColumn::Percent => "%".to_string(),
+            AbilityColumn::Rate => rate_label.to_string(),
+            AbilityColumn::Hits => "Hits".to_string(),
+            AbilityColumn::Avg => "Avg".to_string(),
+            AbilityColumn::Crit => "Crit%".to_string(),
+        }
+    }
+
+    fn value(&self, row: &AbilityBreakdown) -> f64 {
+        match self {
+            AbilityColumn::Total => row.total_value,
+            AbilityColumn::Percent => row.percent_of_total,
+            AbilityColumn::Rate => row.dps,
+            AbilityColumn::Hits => row.hit_count as f64,
+            AbilityColumn::Avg => row.avg_hit,
+            AbilityColumn::Crit => row.crit_rate,
+        }
+    }
+
+    fn format(&self, row: &AbilityBreakdown) -> String {
+        match self {
+            AbilityColumn::Percent | AbilityColumn::Crit => format_pct(self.value(row)),
+            AbilityColumn::Hits => row.hit_count.to_string(),
+            _ => format_number(self.value(row)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AbilitySortTarget {
+    Name,
+    Metric(AbilityColumn),
+}
+
+type AbilitySortKey = (AbilitySortTarget, SortDir);
+
+/// Stable multi-key sort over the ability table, mirroring `sort_rows`.
+fn sort_abilities(rows: &mut [AbilityBreakdown], keys: &[AbilitySortKey]) {
+    rows.sort_by(|a, b| {
+        for (target, dir) in keys {
+            let ord = match target {
+                AbilitySortTarget::Name => a.ability_name.cmp(&b.ability_name),
+                AbilitySortTarget::Metric(col) => col.value(a).partial_cmp(&col.value(b)).unwrap_or(Ordering::Equal),
+            };
+            let ord = if *dir == SortDir::Desc { ord.reverse() } else { ord };
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+        Ordering::Equal
+    });
+}
+
+/// Dimension the ability table can be grouped by, each contributing its own
+/// subtotal row (percentages then recompute against that subtotal rather
+/// than the encounter-wide total).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+enum GroupKey {
+    Ability,
+    TargetType,
+    DamageType,
+}
+
+impl GroupKey {
+    const ALL: [GroupKey; 3] = [GroupKey::Ability, GroupKey::TargetType, GroupKey::DamageType];
+
+    fn label(&self) -> &'static str {
+        match self {
+            GroupKey::Ability => "Ability",
+            GroupKey::TargetType => "Target type",
+            GroupKey::DamageType => "Damage type",
+        }
+    }
+}
+
+struct AbilityGroup {
+    label: String,
+    rows: Vec<AbilityBreakdown>,
+    subtotal_value: f64,
+    subtotal_hits: i64,
+}
+
+/// Fold `rows` into groups keyed by `group_by` (no grouping just wraps
+/// everything into a single unlabeled group), then recompute each row's
+/// `percent_of_total` against its group's subtotal rather than the global
+/// total the query already populated it with.
+fn group_abilities(rows: &[AbilityBreakdown], group_by: Option<GroupKey>) -> Vec<AbilityGroup> {
+    let mut groups: Vec<AbilityGroup> = Vec::new();
+
+    for row in rows {
+        let label = match group_by {
+            None => String::new(),
+            Some(GroupKey::Ability) => row.ability_name.clone(),
+            Some(GroupKey::TargetType) => row.target_name.clone().unwrap_or_else(|| "-".to_string()),
+            Some(GroupKey::DamageType) => row
+                .damage_type_breakdown
+                .as_ref()
+                .and_then(|dts| dts.first())
+                .map(|dt| dt.damage_type.clone())
+                .unwrap_or_else(|| "-".to_string()),
+        };
+
+        match groups.iter_mut().find(|g| g.label == label) {
+            Some(group) => group.rows.push(row.clone()),
+            None => groups.push(AbilityGroup {
+                label,
+                rows: vec![row.clone()],
+                subtotal_value: 0.0,
+                subtotal_hits: 0,
+            }),
+        }
+    }
+
+    for group in &mut groups {
+        group.subtotal_value = group.rows.iter().map(|r| r.total_value).sum();
+        group.subtotal_hits = group.rows.iter().map(|r| r.hit_count).sum();
+        for row in &mut group.rows {
+            row.percent_of_total = if group.subtotal_value > 0.0 {
+                row.total_value / group.subtotal_value * 100.0
+            } else {
+                0.0
+            };
+        }
+    }
+
+    groups
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Entity list: sortable columns
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntitySortTarget {
+    Name,
+    Total,
+    Abilities,
+}
+
+type EntitySortKey = (EntitySortTarget, SortDir);
+
+fn sort_entities(rows: &mut [EntityBreakdown], keys: &[EntitySortKey]) {
+    rows.sort_by(|a, b| {
+        for (target, dir) in keys {
+            let ord = match target {
+                EntitySortTarget::Name => a.source_name.cmp(&b.source_name),
+                EntitySortTarget::Total => a.total_value.partial_cmp(&b.total_value).unwrap_or(Ordering::Equal),
+                EntitySortTarget::Abilities => a.abilities_used.cmp(&b.abilities_used),
+            };
+            let ord = if *dir == SortDir::Desc { ord.reverse() } else { ord };
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+        Ordering::Equal
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Keyboard focus navigation
+//
+// Tab moves the visible focus highlight between the sidebar encounter list
+// and the current content rows (overview table or entity list); ArrowUp/Down
+// move within the focused region; Enter/Space activates the focused item
+// (equivalent to its onclick); `[`/`]` cycle the data tabs. Each focus move
+// updates `announcement`, read by an aria-live region for screen readers.
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusRegion {
+    Sidebar,
+    Rows,
+}
+
+/// Index of the active tab among [Overview, Damage, Healing, DamageTaken, HealingTaken].
+fn tab_index(show_overview: bool, tab: DataTab) -> usize {
+    if show_overview {
+        0
+    } else {
+        match tab {
+            DataTab::Damage => 1,
+            DataTab::Healing => 2,
+            DataTab::DamageTaken => 3,
+            DataTab::HealingTaken => 4,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Text-based time-range entry
+//
+// Lets users type a window instead of dragging the phase timeline slider.
+// Supported forms (all clamped to [0, duration_secs]):
+//   "1:30-2:45"  - explicit mm:ss range
+//   "first 1m"   - the first N seconds/minutes of the encounter
+//   "last 30s"   - the final N seconds/minutes before the encounter ends
+//   "-15s"/"-1m" - shorthand for "last N"
+//   "2:00+15s"   - an mm:ss anchor plus a duration offset
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse "M:SS" into total seconds.
+fn parse_mmss(input: &str) -> Option<f32> {
+    let (min, sec) = input.split_once(':')?;
+    let min: f32 = min.trim().parse().ok()?;
+    let sec: f32 = sec.trim().parse().ok()?;
+    Some(min * 60.0 + sec)
+}
+
+/// Parse a bare duration like "30s" or "1m" into seconds.
+fn parse_offset(input: &str) -> Option<f32> {
+    let input = input.trim();
+    if let Some(n) = input.strip_suffix('s') {
+        n.trim().parse().ok()
+    } else if let Some(n) = input.strip_suffix('m') {
+        n.trim().parse::<f32>().ok().map(|m| m * 60.0)
+    } else {
+        input.parse().ok()
+    }
+}
+
+fn parse_time_range_text(input: &str, duration_secs: f32) -> Option<TimeRange> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+
+    let clamp = |start: f32, end: f32| {
+        let mut start = start.clamp(0.0, duration_secs);
+        let mut end = end.clamp(0.0, duration_secs);
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+        TimeRange { start, end }
+    };
+
+    if let Some(rest) = input.strip_prefix('-') {
+        let secs = parse_offset(rest)?;
+        return Some(clamp(duration_secs - secs, duration_secs));
+    }
+    if let Some(rest) = input.strip_prefix("last ") {
+        let secs = parse_offset(rest)?;
+        return Some(clamp(duration_secs - secs, duration_secs));
+    }
+    if let Some(rest) = input.strip_prefix("first ") {
+        let secs = parse_offset(rest)?;
+        return Some(clamp(0.0, secs));
+    }
+    if let Some((anchor, offset)) = input.split_once('+') {
+        let anchor_secs = parse_mmss(anchor.trim())?;
+        let offset_secs = parse_offset(offset.trim())?;
+        return Some(clamp(anchor_secs, anchor_secs + offset_secs));
+    }
+    if let Some((from, to)) = input.split_once('-') {
+        let start_secs = parse_mmss(from.trim())?;
+        let end_secs = parse_mmss(to.trim())?;
+        return Some(clamp(start_secs, end_secs));
+    }
+
+    None
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Similar-pull comparison
+//
+// Each encounter's ability breakdown is reduced to a feature vector - each
+// ability's fraction of the encounter's total damage/healing - so pulls of
+// different lengths are directly comparable. Candidates sharing boss name
+// and difficulty are ranked by cosine similarity against the current pull.
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Per-ability fraction of the encounter's total, keyed by ability name
+/// (there's no separate numeric ability id in `AbilityBreakdown`).
+type AbilityVector = HashMap<String, f64>;
+
+fn ability_vector(breakdown: &[AbilityBreakdown]) -> AbilityVector {
+    let total: f64 = breakdown.iter().map(|a| a.total_value).sum();
+    if total <= 0.0 {
+        return AbilityVector::new();
+    }
+    breakdown.iter().map(|a| (a.ability_name.clone(), a.total_value / total)).collect()
+}
+
+fn cosine_similarity(a: &AbilityVector, b: &AbilityVector) -> f64 {
+    let keys: HashSet<&String> = a.keys().chain(b.keys()).collect();
+    let (mut dot, mut norm_a, mut norm_b) = (0.0, 0.0, 0.0);
+    for key in keys {
+        let va = a.get(key).copied().unwrap_or(0.0);
+        let vb = b.get(key).copied().unwrap_or(0.0);
+        dot += va * vb;
+        norm_a += va * va;
+        norm_b += vb * vb;
+    }
+    if norm_a <= 0.0 || norm_b <= 0.0 {
+        0.0
+    } else {
+        dot / (norm_a.sqrt() * norm_b.sqrt())
+    }
+}
+
+fn median(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// A past pull of the same boss/difficulty, ranked by similarity to the
+/// currently selected encounter.
+#[derive(Debug, Clone)]
+struct SimilarPull {
+    encounter_idx: u32,
+    display_name: String,
+    similarity: f64,
+}
+
+/// Current vs. best/median across the similar pulls, for one ability.
+#[derive(Debug, Clone)]
+struct AbilityDelta {
+    ability_name: String,
+    current_pct: f64,
+    current_dps: f64,
+    best_pct: f64,
+    best_dps: f64,
+    median_pct: f64,
+}
+
+const SIMILAR_PULLS_TOP_N: usize = 5;
+
+/// Number of time buckets (columns) requested for the entity heatmap view.
+const HEATMAP_COLUMNS: usize = 30;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Helper Functions
 // ─────────────────────────────────────────────────────────────────────────────
@@ -81,6 +648,17 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     let mut loading = use_signal(|| false);
     let mut error_msg = use_signal(|| None::<String>);
 
+    // Ability drill-down: per-hit histogram for the ability row last clicked
+    let mut selected_ability = use_signal(|| None::<String>);
+    let mut histogram = use_signal(Vec::<HistoPoint>::new);
+    let mut histogram_loading = use_signal(|| false);
+
+    // Entity timeline heatmap: alternative to the entity list, one row per
+    // entity and one column per time bucket
+    let mut show_heatmap = use_signal(|| false);
+    let mut heatmap_rows = use_signal(Vec::<HeatmapRow>::new);
+    let mut heatmap_loading = use_signal(|| false);
+
     // Entity filter: true = players/companions only, false = show all (including NPCs)
     let mut show_players_only = use_signal(|| true);
 
@@ -88,6 +666,10 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     let mut timeline = use_signal(|| None::<EncounterTimeline>);
     let mut time_range = use_signal(|| TimeRange::default());
 
+    // Free-text time-range entry, parsed alongside the slider
+    let mut time_range_text = use_signal(String::new);
+    let mut time_range_text_error = use_signal(|| None::<String>);
+
     // Breakdown mode state (toggles for grouping)
     let mut breakdown_mode = use_signal(|| BreakdownMode::ability_only());
 
@@ -98,6 +680,35 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     let mut show_overview = use_signal(|| true);
     let mut overview_data = use_signal(Vec::<RaidOverviewRow>::new);
 
+    // "Compare to history" mode - feature vectors of past pulls are cached by
+    // (tab, encounter index) so re-comparing skips the query for any pull
+    // already seen this session, but switching tabs doesn't serve up another
+    // tab's breakdown for the same pull.
+    let mut compare_mode = use_signal(|| false);
+    let mut comparing = use_signal(|| false);
+    let mut compare_cache = use_signal(HashMap::<(DataTab, u32), Vec<AbilityBreakdown>>::new);
+    let mut similar_pulls = use_signal(Vec::<SimilarPull>::new);
+    let mut ability_deltas = use_signal(Vec::<AbilityDelta>::new);
+
+    // Overview table column configuration and sort keys, persisted to local
+    // storage so the layout survives a reload.
+    let mut overview_columns = use_signal(|| load_from_storage(OVERVIEW_COLUMNS_STORAGE_KEY).unwrap_or_else(default_overview_columns));
+    let mut overview_sort_keys = use_signal(|| load_from_storage::<Vec<SortKey>>(OVERVIEW_SORT_STORAGE_KEY).unwrap_or_default());
+    let mut show_column_picker = use_signal(|| false);
+
+    // Ability table sort + group-by state
+    let mut ability_sort_keys = use_signal(Vec::<AbilitySortKey>::new);
+    let mut group_by = use_signal(|| None::<GroupKey>);
+
+    // Entity list sort state
+    let mut entity_sort_keys = use_signal(Vec::<EntitySortKey>::new);
+
+    // Keyboard focus navigation - which region has the visible highlight,
+    // the focused row's index within it, and the latest aria-live announcement.
+    let mut focus_region = use_signal(|| FocusRegion::Sidebar);
+    let mut focused_index = use_signal(|| 0usize);
+    let mut announcement = use_signal(String::new);
+
     // Load encounter list on mount
     use_effect(move || {
         spawn(async move {
@@ -264,6 +875,52 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         });
     });
 
+    // Fetch the per-hit histogram whenever the drilled-down ability (or the
+    // context it's scoped to) changes; clears the panel when deselected.
+    use_effect(move || {
+        let ability_name = selected_ability.read().clone();
+        let idx = *selected_encounter.read();
+        let tab = *selected_tab.read();
+        let src = selected_source.read().clone();
+        let tr = time_range();
+
+        let Some(ability_name) = ability_name else {
+            histogram.set(Vec::new());
+            return;
+        };
+
+        spawn(async move {
+            histogram_loading.set(true);
+            let tr_opt = if tr.start == 0.0 && tr.end == 0.0 { None } else { Some(tr) };
+            if let Some(data) = api::query_ability_histogram(tab, idx, src, ability_name, tr_opt.as_ref()).await {
+                histogram.set(data);
+            }
+            histogram_loading.set(false);
+        });
+    });
+
+    // Fetch the entity heatmap while the heatmap view is active, reacting to
+    // the same context the entity/ability queries use.
+    use_effect(move || {
+        let show = *show_heatmap.read();
+        let idx = *selected_encounter.read();
+        let tab = *selected_tab.read();
+        let tr = time_range();
+
+        if !show {
+            return;
+        }
+
+        spawn(async move {
+            heatmap_loading.set(true);
+            let tr_opt = if tr.start == 0.0 && tr.end == 0.0 { None } else { Some(tr) };
+            if let Some(data) = api::query_entity_heatmap(tab, idx, HEATMAP_COLUMNS, tr_opt.as_ref()).await {
+                heatmap_rows.set(data);
+            }
+            heatmap_loading.set(false);
+        });
+    });
+
     // Filter by source when selected
     let mut on_source_click = move |name: String| {
         let idx = *selected_encounter.read();
@@ -300,6 +957,230 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         });
     };
 
+    // Keyboard navigation: Tab switches region, arrows move within it,
+    // Enter/Space activates, `[`/`]` cycle data tabs.
+    let mut on_keydown = move |evt: KeyboardEvent| {
+        let key = evt.key().to_string();
+
+        let mut set_tab = |idx: usize| match idx {
+            0 => show_overview.set(true),
+            1 => {
+                show_overview.set(false);
+                selected_tab.set(DataTab::Damage);
+            }
+            2 => {
+                show_overview.set(false);
+                selected_tab.set(DataTab::Healing);
+            }
+            3 => {
+                show_overview.set(false);
+                selected_tab.set(DataTab::DamageTaken);
+            }
+            _ => {
+                show_overview.set(false);
+                selected_tab.set(DataTab::HealingTaken);
+            }
+        };
+
+        match key.as_str() {
+            "Tab" => {
+                evt.prevent_default();
+                focus_region.set(match focus_region() {
+                    FocusRegion::Sidebar => FocusRegion::Rows,
+                    FocusRegion::Rows => FocusRegion::Sidebar,
+                });
+                focused_index.set(0);
+            }
+            "ArrowDown" | "ArrowUp" => {
+                evt.prevent_default();
+                let delta: i64 = if key == "ArrowDown" { 1 } else { -1 };
+
+                match focus_region() {
+                    FocusRegion::Sidebar => {
+                        let history = encounters();
+                        let history: Vec<_> = if show_only_bosses() {
+                            history.iter().filter(|e| e.boss_name.is_some()).cloned().collect()
+                        } else {
+                            history
+                        };
+                        if history.is_empty() {
+                            return;
+                        }
+                        let idx = (focused_index() as i64 + delta).rem_euclid(history.len() as i64) as usize;
+                        focused_index.set(idx);
+                        let enc = &history[idx];
+                        let result = if enc.success { "clear" } else { "wipe" };
+                        announcement.set(format!("{}, {}, {}", enc.display_name, result, format_duration(enc.duration_seconds)));
+                    }
+                    FocusRegion::Rows if show_overview() => {
+                        let mut rows: Vec<_> = overview_data.read().iter()
+                            .filter(|r| r.entity_type == "Player" || r.entity_type == "Companion")
+                            .cloned()
+                            .collect();
+                        sort_rows(&mut rows, &overview_sort_keys());
+                        if rows.is_empty() {
+                            return;
+                        }
+                        let idx = (focused_index() as i64 + delta).rem_euclid(rows.len() as i64) as usize;
+                        focused_index.set(idx);
+                        let row = &rows[idx];
+                        announcement.set(format!("{}, DPS {}, damage total {}", row.name, format_number(row.dps), format_number(row.damage_total)));
+                    }
+                    FocusRegion::Rows => {
+                        let players_only = show_players_only();
+                        let mut entity_list: Vec<_> = entities.read().iter()
+                            .filter(|e| !players_only || e.entity_type == "Player" || e.entity_type == "Companion")
+                            .cloned()
+                            .collect();
+                        sort_entities(&mut entity_list, &entity_sort_keys());
+                        if entity_list.is_empty() {
+                            return;
+                        }
+                        let idx = (focused_index() as i64 + delta).rem_euclid(entity_list.len() as i64) as usize;
+                        focused_index.set(idx);
+                        let entity = &entity_list[idx];
+                        announcement.set(format!("{}, {}, {} abilities", entity.source_name, format_number(entity.total_value), entity.abilities_used));
+                    }
+                }
+            }
+            "Enter" | " " => {
+                evt.prevent_default();
+                match focus_region() {
+                    FocusRegion::Sidebar => {
+                        let history = encounters();
+                        let history: Vec<_> = if show_only_bosses() {
+                            history.iter().filter(|e| e.boss_name.is_some()).cloned().collect()
+                        } else {
+                            history
+                        };
+                        if history.get(focused_index()).is_some() {
+                            selected_encounter.set(Some(focused_index() as u32));
+                        }
+                    }
+                    FocusRegion::Rows if !show_overview() => {
+                        let players_only = show_players_only();
+                        let mut entity_list: Vec<_> = entities.read().iter()
+                            .filter(|e| !players_only || e.entity_type == "Player" || e.entity_type == "Companion")
+                            .cloned()
+                            .collect();
+                        sort_entities(&mut entity_list, &entity_sort_keys());
+                        if let Some(entity) = entity_list.get(focused_index()) {
+                            on_source_click(entity.source_name.clone());
+                        }
+                    }
+                    FocusRegion::Rows => {}
+                }
+            }
+            "]" => {
+                evt.prevent_default();
+                set_tab((tab_index(show_overview(), selected_tab()) + 1) % 5);
+            }
+            "[" => {
+                evt.prevent_default();
+                set_tab((tab_index(show_overview(), selected_tab()) + 4) % 5);
+            }
+            _ => {}
+        }
+    };
+
+    // Find the most similar past pulls of the same boss/difficulty and
+    // build a per-ability delta table against them.
+    let mut find_similar = move || {
+        let Some(idx) = *selected_encounter.read() else { return };
+        let tab = *selected_tab.read();
+        let history = encounters();
+        let Some(current_enc) = history.get(idx as usize).cloned() else { return };
+        let Some(boss_name) = current_enc.boss_name.clone() else {
+            similar_pulls.set(Vec::new());
+            ability_deltas.set(Vec::new());
+            return;
+        };
+        let difficulty = current_enc.difficulty.clone();
+
+        spawn(async move {
+            comparing.set(true);
+
+            let current_breakdown = api::query_breakdown(tab, Some(idx), None, None, None, Some(&BreakdownMode::ability_only()), None)
+                .await
+                .unwrap_or_default();
+            let current_vector = ability_vector(&current_breakdown);
+
+            let mut candidates: Vec<(u32, f64, Vec<AbilityBreakdown>)> = Vec::new();
+            for (cand_idx, enc) in history.iter().enumerate() {
+                let cand_idx = cand_idx as u32;
+                if cand_idx == idx || enc.boss_name.as_ref() != Some(&boss_name) || enc.difficulty != difficulty {
+                    continue;
+                }
+
+                let cached = compare_cache.read().get(&(tab, cand_idx)).cloned();
+                let breakdown = match cached {
+                    Some(b) => b,
+                    None => {
+                        let Some(b) = api::query_breakdown(tab, Some(cand_idx), None, None, None, Some(&BreakdownMode::ability_only()), None).await else {
+                            continue;
+                        };
+                        compare_cache.write().insert((tab, cand_idx), b.clone());
+                        b
+                    }
+                };
+
+                let similarity = cosine_similarity(&current_vector, &ability_vector(&breakdown));
+                candidates.push((cand_idx, similarity, breakdown));
+            }
+
+            candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+            candidates.truncate(SIMILAR_PULLS_TOP_N);
+
+            similar_pulls.set(
+                candidates
+                    .iter()
+                    .map(|(cand_idx, similarity, _)| SimilarPull {
+                        encounter_idx: *cand_idx,
+                        display_name: history.get(*cand_idx as usize).map(|e| e.display_name.clone()).unwrap_or_default(),
+                        similarity: *similarity,
+                    })
+                    .collect(),
+            );
+
+            // Union of ability names seen in the current pull or any similar pull.
+            let mut ability_names: Vec<String> = current_breakdown.iter().map(|a| a.ability_name.clone()).collect();
+            for (_, _, breakdown) in &candidates {
+                for ability in breakdown {
+                    if !ability_names.contains(&ability.ability_name) {
+                        ability_names.push(ability.ability_name.clone());
+                    }
+                }
+            }
+
+            let deltas = ability_names
+                .into_iter()
+                .map(|name| {
+                    let current = current_breakdown.iter().find(|a| a.ability_name == name);
+                    let pcts: Vec<f64> = candidates
+                        .iter()
+                        .map(|(_, _, b)| b.iter().find(|a| a.ability_name == name).map(|a| a.percent_of_total).unwrap_or(0.0))
+                        .collect();
+                    let dpses: Vec<f64> = candidates
+                        .iter()
+                        .map(|(_, _, b)| b.iter().find(|a| a.ability_name == name).map(|a| a.dps).unwrap_or(0.0))
+                        .collect();
+
+                    AbilityDelta {
+                        ability_name: name,
+                        current_pct: current.map(|a| a.percent_of_total).unwrap_or(0.0),
+                        current_dps: current.map(|a| a.dps).unwrap_or(0.0),
+                        best_pct: pcts.iter().cloned().fold(0.0_f64, f64::max),
+                        best_dps: dpses.iter().cloned().fold(0.0_f64, f64::max),
+                        median_pct: median(&pcts),
+                    }
+                })
+                .collect();
+            ability_deltas.set(deltas);
+
+            comparing.set(false);
+        });
+    };
+
     // Prepare data for rendering
     let history = encounters();
     let bosses_only = show_only_bosses();
@@ -316,7 +1197,19 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     let sections = group_by_area(&filtered_history);
 
     rsx! {
-        div { class: "data-explorer",
+        div {
+            class: "data-explorer",
+            tabindex: "0",
+            onkeydown: on_keydown,
+
+            // Screen-reader announcement of whatever row currently has focus.
+            div {
+                class: "sr-only",
+                "aria-live": "polite",
+                "aria-atomic": "true",
+                "{announcement}"
+            }
+
             // Sidebar with encounter list
             aside { class: "explorer-sidebar",
                 div { class: "sidebar-header",
@@ -387,10 +1280,17 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                 let enc_idx = global_idx.unwrap_or(enc_offset as u32);
                                                 let is_selected = *selected_encounter.read() == Some(enc_idx);
                                                 let success_class = if enc.success { "success" } else { "wipe" };
+                                                let is_focused = focus_region() == FocusRegion::Sidebar && focused_index() as u32 == enc_idx;
+                                                let item_class = match (is_selected, is_focused) {
+                                                    (true, true) => "sidebar-encounter-item selected focused",
+                                                    (true, false) => "sidebar-encounter-item selected",
+                                                    (false, true) => "sidebar-encounter-item focused",
+                                                    (false, false) => "sidebar-encounter-item",
+                                                };
 
                                                 rsx! {
                                                     div {
-                                                        class: if is_selected { "sidebar-encounter-item selected" } else { "sidebar-encounter-item" },
+                                                        class: "{item_class}",
                                                         onclick: move |_| selected_encounter.set(Some(enc_idx)),
                                                         div { class: "encounter-main",
                                                             span { class: "encounter-name", "{enc.display_name}" }
@@ -431,11 +1331,47 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                 } else {
                     // Phase timeline filter (when timeline is loaded)
                     if let Some(tl) = timeline.read().as_ref() {
-                        PhaseTimelineFilter {
-                            timeline: tl.clone(),
-                            range: time_range(),
-                            on_range_change: move |new_range: TimeRange| {
-                                time_range.set(new_range);
+                        {
+                            let duration_secs = tl.duration_secs;
+                            rsx! {
+                                PhaseTimelineFilter {
+                                    timeline: tl.clone(),
+                                    range: time_range(),
+                                    on_range_change: move |new_range: TimeRange| {
+                                        time_range.set(new_range);
+                                    }
+                                }
+
+                                // Free-text alternative to dragging the slider, e.g. "last 30s" or "1:30-2:45"
+                                div { class: "time-range-entry",
+                                    input {
+                                        class: "time-range-input",
+                                        r#type: "text",
+                                        placeholder: "e.g. last 30s, 1:30-2:45, first 1m, 2:00+15s",
+                                        value: "{time_range_text}",
+                                        oninput: move |e| {
+                                            time_range_text.set(e.value());
+                                            time_range_text_error.set(None);
+                                        },
+                                        onkeydown: move |e| {
+                                            if e.key().to_string() == "Enter" {
+                                                let text = time_range_text();
+                                                match parse_time_range_text(&text, duration_secs) {
+                                                    Some(range) => {
+                                                        time_range.set(range);
+                                                        time_range_text_error.set(None);
+                                                    }
+                                                    None => {
+                                                        time_range_text_error.set(Some(format!("Couldn't parse \"{text}\"")));
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if let Some(err) = time_range_text_error.read().as_ref() {
+                                        span { class: "time-range-error", "{err}" }
+                                    }
+                                }
                             }
                         }
                     }
@@ -474,56 +1410,194 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                         div { class: "error-message", "{err}" }
                     }
 
+                    // Compare to history - similar past pulls of the same boss/difficulty
+                    div { class: "compare-section",
+                        button {
+                            class: if *compare_mode.read() { "compare-toggle active" } else { "compare-toggle" },
+                            onclick: move |_| {
+                                let enabled = !compare_mode();
+                                compare_mode.set(enabled);
+                                if enabled {
+                                    find_similar();
+                                }
+                            },
+                            "Compare to History"
+                        }
+                        if *compare_mode.read() {
+                            if *comparing.read() {
+                                div { class: "compare-loading", "Finding similar pulls…" }
+                            } else if similar_pulls.read().is_empty() {
+                                p { class: "hint", "No comparable pulls found for this boss and difficulty yet." }
+                            } else {
+                                div { class: "compare-results",
+                                    div { class: "similar-pulls-list",
+                                        h5 { "Most similar pulls" }
+                                        for pull in similar_pulls.read().iter() {
+                                            div { class: "similar-pull-row", key: "{pull.encounter_idx}",
+                                                span { class: "similar-pull-name", "{pull.display_name}" }
+                                                span { class: "similar-pull-score", "{format_pct(pull.similarity * 100.0)} match" }
+                                            }
+                                        }
+                                    }
+                                    table { class: "ability-delta-table",
+                                        thead {
+                                            tr {
+                                                th { "Ability" }
+                                                th { class: "num", "Current %" }
+                                                th { class: "num", "Best %" }
+                                                th { class: "num", "Median %" }
+                                                th { class: "num", "Current DPS" }
+                                                th { class: "num", "Best DPS" }
+                                            }
+                                        }
+                                        tbody {
+                                            for delta in ability_deltas.read().iter() {
+                                                {
+                                                    let perf_class = if delta.current_pct + 0.01 < delta.median_pct {
+                                                        "delta-row under"
+                                                    } else if delta.current_pct > delta.median_pct + 0.01 {
+                                                        "delta-row over"
+                                                    } else {
+                                                        "delta-row even"
+                                                    };
+                                                    rsx! {
+                                                        tr { class: "{perf_class}", key: "{delta.ability_name}",
+                                                            td { "{delta.ability_name}" }
+                                                            td { class: "num", "{format_pct(delta.current_pct)}" }
+                                                            td { class: "num", "{format_pct(delta.best_pct)}" }
+                                                            td { class: "num", "{format_pct(delta.median_pct)}" }
+                                                            td { class: "num", "{format_number(delta.current_dps)}" }
+                                                            td { class: "num", "{format_number(delta.best_dps)}" }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     // Content area - Overview or Detailed view
                     if *show_overview.read() {
                         // Raid Overview Table
                         div { class: "overview-section",
+                            div { class: "overview-toolbar",
+                                button {
+                                    class: "column-picker-toggle",
+                                    onclick: move |_| show_column_picker.set(!show_column_picker()),
+                                    "Columns ▾"
+                                }
+                                if show_column_picker() {
+                                    div { class: "column-picker",
+                                        for col in OverviewColumn::ALL.iter().copied() {
+                                            div { class: "column-picker-row", key: "{col:?}",
+                                                label {
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        checked: overview_columns.read().contains(&col),
+                                                        onchange: move |_| {
+                                                            let mut cols = overview_columns();
+                                                            if let Some(pos) = cols.iter().position(|c| *c == col) {
+                                                                cols.remove(pos);
+                                                            } else {
+                                                                cols.push(col);
+                                                            }
+                                                            save_to_storage(OVERVIEW_COLUMNS_STORAGE_KEY, &cols);
+                                                            overview_columns.set(cols);
+                                                        }
+                                                    }
+                                                    "{col.picker_label()}"
+                                                }
+                                                button {
+                                                    class: "column-move-btn",
+                                                    disabled: overview_columns.read().first() == Some(&col),
+                                                    onclick: move |_| {
+                                                        let mut cols = overview_columns();
+                                                        if let Some(pos) = cols.iter().position(|c| *c == col) {
+                                                            if pos > 0 {
+                                                                cols.swap(pos, pos - 1);
+                                                                save_to_storage(OVERVIEW_COLUMNS_STORAGE_KEY, &cols);
+                                                                overview_columns.set(cols);
+                                                            }
+                                                        }
+                                                    },
+                                                    "↑"
+                                                }
+                                                button {
+                                                    class: "column-move-btn",
+                                                    disabled: overview_columns.read().last() == Some(&col),
+                                                    onclick: move |_| {
+                                                        let mut cols = overview_columns();
+                                                        if let Some(pos) = cols.iter().position(|c| *c == col) {
+                                                            if pos + 1 < cols.len() {
+                                                                cols.swap(pos, pos + 1);
+                                                                save_to_storage(OVERVIEW_COLUMNS_STORAGE_KEY, &cols);
+                                                                overview_columns.set(cols);
+                                                            }
+                                                        }
+                                                    },
+                                                    "↓"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
                             {
                                 // Filter to only show Players/Companions
-                                let rows: Vec<_> = overview_data.read().iter()
+                                let mut rows: Vec<_> = overview_data.read().iter()
                                     .filter(|r| r.entity_type == "Player" || r.entity_type == "Companion")
                                     .cloned()
                                     .collect();
+                                let sort_keys = overview_sort_keys.read().clone();
+                                sort_rows(&mut rows, &sort_keys);
+                                let columns = overview_columns.read().clone();
                                 rsx! {
                                     table { class: "overview-table",
                                         thead {
                                             tr {
-                                                th { class: "name-col", "Name" }
-                                                th { class: "section-header", colspan: "2", "Damage Dealt" }
-                                                th { class: "section-header", colspan: "2", "Threat" }
-                                                th { class: "section-header", colspan: "3", "Damage Taken" }
-                                                th { class: "section-header", colspan: "4", "Healing" }
-                                            }
-                                            tr { class: "sub-header",
-                                                th {}
-                                                th { class: "num", "Total" }
-                                                th { class: "num", "DPS" }
-                                                th { class: "num", "Total" }
-                                                th { class: "num", "TPS" }
-                                                th { class: "num", "Total" }
-                                                th { class: "num", "DTPS" }
-                                                th { class: "num", "APS" }
-                                                th { class: "num", "Total" }
-                                                th { class: "num", "HPS" }
-                                                th { class: "num", "%" }
-                                                th { class: "num", "EHPS" }
+                                                th {
+                                                    class: "name-col sortable",
+                                                    onclick: move |_| {
+                                                        let mut keys = overview_sort_keys();
+                                                        toggle_sort_key(&mut keys, SortTarget::Name);
+                                                        save_to_storage(OVERVIEW_SORT_STORAGE_KEY, &keys);
+                                                        overview_sort_keys.set(keys);
+                                                    },
+                                                    "Name"
+                                                    if let Some(indicator) = sort_indicator(SortTarget::Name, &sort_keys) {
+                                                        span { class: "sort-indicator", " {indicator}" }
+                                                    }
+                                                }
+                                                for col in columns.iter().copied() {
+                                                    th {
+                                                        class: "num sortable",
+                                                        key: "{col:?}",
+                                                        onclick: move |_| {
+                                                            let mut keys = overview_sort_keys();
+                                                            toggle_sort_key(&mut keys, SortTarget::Metric(col));
+                                                            save_to_storage(OVERVIEW_SORT_STORAGE_KEY, &keys);
+                                                            overview_sort_keys.set(keys);
+                                                        },
+                                                        "{col.header_label()}"
+                                                        if let Some(indicator) = sort_indicator(SortTarget::Metric(col), &sort_keys) {
+                                                            span { class: "sort-indicator", " {indicator}" }
+                                                        }
+                                                    }
+                                                }
                                             }
                                         }
                                         tbody {
-                                            for row in rows.iter() {
+                                            for (row_idx, row) in rows.iter().enumerate() {
                                                 tr {
+                                                    key: "{row.name}",
+                                                    class: if focus_region() == FocusRegion::Rows && focused_index() == row_idx { "focused" } else { "" },
                                                     td { class: "name-col", "{row.name}" }
-                                                    td { class: "num dmg", "{format_number(row.damage_total)}" }
-                                                    td { class: "num dmg", "{format_number(row.dps)}" }
-                                                    td { class: "num threat", "{format_number(row.threat_total)}" }
-                                                    td { class: "num threat", "{format_number(row.tps)}" }
-                                                    td { class: "num taken", "{format_number(row.damage_taken_total)}" }
-                                                    td { class: "num taken", "{format_number(row.dtps)}" }
-                                                    td { class: "num taken", "{format_number(row.aps)}" }
-                                                    td { class: "num heal", "{format_number(row.healing_total)}" }
-                                                    td { class: "num heal", "{format_number(row.hps)}" }
-                                                    td { class: "num heal", "{format_pct(row.healing_pct)}" }
-                                                    td { class: "num heal", "{format_number(row.ehps)}" }
+                                                    for col in columns.iter().copied() {
+                                                        td { class: "{col.css_class()}", key: "{col:?}", "{col.format(row)}" }
+                                                    }
                                                 }
                                             }
                                         }
@@ -551,31 +1625,76 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                             onclick: move |_| show_players_only.set(false),
                                             "All"
                                         }
+                                        button {
+                                            class: if *show_heatmap.read() { "filter-tab active" } else { "filter-tab" },
+                                            onclick: move |_| show_heatmap.set(!show_heatmap()),
+                                            "Heatmap"
+                                        }
+                                    }
+                                    div { class: "entity-sort-tabs",
+                                        {
+                                            let keys = entity_sort_keys.read().clone();
+                                            rsx! {
+                                                for (label, target) in [("Name", EntitySortTarget::Name), ("Total", EntitySortTarget::Total), ("Abilities", EntitySortTarget::Abilities)] {
+                                                    button {
+                                                        class: "sort-tab",
+                                                        key: "{label}",
+                                                        onclick: move |_| {
+                                                            let mut keys = entity_sort_keys();
+                                                            toggle_sort_key(&mut keys, target);
+                                                            entity_sort_keys.set(keys);
+                                                        },
+                                                        "{label}"
+                                                        if let Some(ind) = sort_indicator(target, &keys) {
+                                                            span { class: "sort-indicator", " {ind}" }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
                                     }
                                 }
-                                div { class: "entity-list",
-                                    {
-                                        let players_only = *show_players_only.read();
-                                        let entity_list: Vec<_> = entities.read().iter()
-                                            .filter(|e| !players_only || e.entity_type == "Player" || e.entity_type == "Companion")
-                                            .cloned()
-                                            .collect();
-                                        rsx! {
-                                            for entity in entity_list.iter() {
-                                                {
-                                                    let name = entity.source_name.clone();
-                                                    let is_selected = selected_source.read().as_ref() == Some(&name);
-                                                    let is_npc = entity.entity_type == "Npc";
-                                                    rsx! {
-                                                        div {
-                                                            class: if is_selected { "entity-row selected" } else if is_npc { "entity-row npc" } else { "entity-row" },
-                                                            onclick: {
-                                                                let name = name.clone();
-                                                                move |_| on_source_click(name.clone())
-                                                            },
-                                                            span { class: "entity-name", "{entity.source_name}" }
-                                                            span { class: "entity-value", "{format_number(entity.total_value)}" }
-                                                            span { class: "entity-abilities", "{entity.abilities_used} abilities" }
+                                if *show_heatmap.read() {
+                                    EntityHeatmapPanel {
+                                        rows: heatmap_rows.read().clone(),
+                                        loading: *heatmap_loading.read(),
+                                        rate_label: selected_tab.read().rate_label().to_string(),
+                                    }
+                                } else {
+                                    div { class: "entity-list",
+                                        {
+                                            let players_only = *show_players_only.read();
+                                            let mut entity_list: Vec<_> = entities.read().iter()
+                                                .filter(|e| !players_only || e.entity_type == "Player" || e.entity_type == "Companion")
+                                                .cloned()
+                                                .collect();
+                                            sort_entities(&mut entity_list, &entity_sort_keys.read());
+                                            rsx! {
+                                                for (entity_idx, entity) in entity_list.iter().enumerate() {
+                                                    {
+                                                        let name = entity.source_name.clone();
+                                                        let is_selected = selected_source.read().as_ref() == Some(&name);
+                                                        let is_npc = entity.entity_type == "Npc";
+                                                        let is_focused = focus_region() == FocusRegion::Rows && focused_index() == entity_idx;
+                                                        let row_class = match (is_selected, is_npc, is_focused) {
+                                                            (true, _, true) => "entity-row selected focused",
+                                                            (true, _, false) => "entity-row selected",
+                                                            (false, true, true) => "entity-row npc focused",
+                                                            (false, true, false) => "entity-row npc",
+                                                            (false, false, true) => "entity-row focused",
+                                                            (false, false, false) => "entity-row",
+                                                        };
+                                                        rsx! {
+                                                            div {
+                                                                class: "{row_class}",
+                                                                onclick: {
+                                                                    let name = name.clone();
+                                                                    move |_| on_source_click(name.clone())
+                                                                },
+                                                                span { class: "entity-name", "{entity.source_name}" }
+                                                                span { class: "entity-value", "{format_number(entity.total_value)}" }
+                                                                span { class: "entity-abilities", "{entity.abilities_used} abilities" }
+                                                            }
                                                         }
                                                     }
                                                 }
@@ -644,12 +1763,40 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                         }
                                                         "{instance_label}"
                                                     }
+                                                    label { class: "breakdown-option",
+                                                        input {
+                                                            r#type: "checkbox",
+                                                            checked: breakdown_mode.read().by_damage_type,
+                                                            onchange: move |e| {
+                                                                let mut mode = *breakdown_mode.read();
+                                                                mode.by_damage_type = e.checked();
+                                                                breakdown_mode.set(mode);
+                                                            }
+                                                        }
+                                                        "Damage type"
+                                                    }
                                                 }
                                             }
                                         }
                                     }
                                 }
                                 }
+                                // Group-by selector: subtotal the table by ability, target
+                                // type, or damage type instead of showing flat rows.
+                                div { class: "ability-groupby",
+                                    span { class: "groupby-label", "Group by" }
+                                    select {
+                                        value: match *group_by.read() { None => "none".to_string(), Some(k) => format!("{k:?}") },
+                                        onchange: move |e| {
+                                            let v = e.value();
+                                            group_by.set(GroupKey::ALL.iter().copied().find(|k| format!("{k:?}") == v));
+                                        },
+                                        option { value: "none", "None" }
+                                        for key in GroupKey::ALL.iter().copied() {
+                                            option { value: "{key:?}", key: "{key:?}", "{key.label()}" }
+                                        }
+                                    }
+                                }
                                 // Table with dynamic columns
                                 {
                                 let mode = *breakdown_mode.read();
@@ -657,6 +1804,11 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                 let show_breakdown_col = mode.by_target_type || mode.by_target_instance;
                                 let breakdown_col_label = if tab.is_outgoing() { "Target" } else { "Source" };
                                 let rate_label = tab.rate_label();
+                                let sort_keys = ability_sort_keys.read().clone();
+                                let mut sorted_rows = abilities.read().clone();
+                                sort_abilities(&mut sorted_rows, &sort_keys);
+                                let groups = group_abilities(&sorted_rows, *group_by.read());
+                                let colspan = if show_breakdown_col { 8 } else { 7 };
                                 rsx! {
                                     table { class: "ability-table",
                                         thead {
@@ -664,44 +1816,220 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                 if show_breakdown_col {
                                                     th { "{breakdown_col_label}" }
                                                 }
-                                                th { "Ability" }
-                                                th { class: "num", "Total" }
-                                                th { class: "num", "%" }
-                                                th { class: "num", "{rate_label}" }
-                                                th { class: "num", "Hits" }
-                                                th { class: "num", "Avg" }
-                                                th { class: "num", "Crit%" }
+                                                th {
+                                                    class: "sortable",
+                                                    onclick: move |_| {
+                                                        let mut keys = ability_sort_keys();
+                                                        toggle_sort_key(&mut keys, AbilitySortTarget::Name);
+                                                        ability_sort_keys.set(keys);
+                                                    },
+                                                    "Ability"
+                                                    if let Some(ind) = sort_indicator(AbilitySortTarget::Name, &sort_keys) {
+                                                        span { class: "sort-indicator", " {ind}" }
+                                                    }
+                                                }
+                                                for col in AbilityColumn::ALL.iter().copied() {
+                                                    th {
+                                                        class: "num sortable",
+                                                        key: "{col:?}",
+                                                        onclick: move |_| {
+                                                            let mut keys = ability_sort_keys();
+                                                            toggle_sort_key(&mut keys, AbilitySortTarget::Metric(col));
+                                                            ability_sort_keys.set(keys);
+                                                        },
+                                                        "{col.header_label(rate_label)}"
+                                                        if let Some(ind) = sort_indicator(AbilitySortTarget::Metric(col), &sort_keys) {
+                                                            span { class: "sort-indicator", " {ind}" }
+                                                        }
+                                                    }
+                                                }
                                             }
                                         }
                                         tbody {
-                                            for ability in abilities.read().iter() {
-                                                tr {
-                                                    if show_breakdown_col {
-                                                        td { class: "target-cell",
-                                                            {ability.target_name.as_deref().unwrap_or("-")}
-                                                            // Show @M:SS when instance mode is on
-                                                            if let Some(first_hit) = ability.target_first_hit_secs {
-                                                                span { class: "target-time",
-                                                                    " @{(first_hit as i32) / 60}:{(first_hit as i32) % 60:02}"
+                                            for group in groups.iter() {
+                                                {
+                                                    let show_group_header = group_by.read().is_some();
+                                                    let group_label = group.label.clone();
+                                                    let subtotal_value = group.subtotal_value;
+                                                    let subtotal_hits = group.subtotal_hits;
+                                                    rsx! {
+                                                    if show_group_header {
+                                                        tr { class: "ability-group-header", key: "group-{group_label}",
+                                                            td { colspan: "{colspan}",
+                                                                span { class: "group-label", "{group_label}" }
+                                                                span { class: "group-subtotal",
+                                                                    " — {format_number(subtotal_value)} ({subtotal_hits} hits)"
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                    for ability in group.rows.iter() {
+                                                        {
+                                                            let ability_name = ability.ability_name.clone();
+                                                            let is_selected = selected_ability.read().as_ref() == Some(&ability_name);
+                                                            rsx! {
+                                                            tr {
+                                                            class: if is_selected { "ability-row selected" } else { "ability-row" },
+                                                            onclick: move |_| {
+                                                                let current = selected_ability.read().clone();
+                                                                selected_ability.set(if current.as_ref() == Some(&ability_name) { None } else { Some(ability_name.clone()) });
+                                                            },
+                                                            if show_breakdown_col {
+                                                                td { class: "target-cell",
+                                                                    {ability.target_name.as_deref().unwrap_or("-")}
+                                                                    // Show @M:SS when instance mode is on
+                                                                    if let Some(first_hit) = ability.target_first_hit_secs {
+                                                                        span { class: "target-time",
+                                                                            " @{(first_hit as i32) / 60}:{(first_hit as i32) % 60:02}"
+                                                                        }
+                                                                    }
                                                                 }
                                                             }
+                                                            td { "{ability.ability_name}" }
+                                                            td { class: "num", "{format_number(ability.total_value)}" }
+                                                            td { class: "num pct-cell",
+                                                                span { class: "pct-bar", style: "width: {ability.percent_of_total.min(100.0)}%;" }
+                                                                span { class: "pct-text", "{format_pct(ability.percent_of_total)}" }
+                                                            }
+                                                            td { class: "num", "{format_number(ability.dps)}" }
+                                                            td { class: "num", "{ability.hit_count}" }
+                                                            td { class: "num", "{format_number(ability.avg_hit)}" }
+                                                            td { class: "num", "{format_pct(ability.crit_rate)}" }
+                                                            }
+                                                            if mode.by_damage_type {
+                                                                for dt in ability.damage_type_breakdown.iter().flatten() {
+                                                                    tr { class: "ability-row damage-type-row", key: "{dt.damage_type}",
+                                                                        if show_breakdown_col {
+                                                                            td { class: "target-cell" }
+                                                                        }
+                                                                        td { class: "damage-type-cell", "↳ {dt.damage_type}" }
+                                                                        td { class: "num", "{format_number(dt.total_value)}" }
+                                                                        td { class: "num pct-cell",
+                                                                            span { class: "pct-bar", style: "width: {dt.percent_of_total.min(100.0)}%;" }
+                                                                            span { class: "pct-text", "{format_pct(dt.percent_of_total)}" }
+                                                                        }
+                                                                        td { class: "num", "{format_number(dt.dps)}" }
+                                                                        td { class: "num", "{dt.hit_count}" }
+                                                                        td { class: "num", "{format_number(dt.avg_hit)}" }
+                                                                        td { class: "num", "{format_pct(dt.crit_rate)}" }
+                                                                    }
+                                                                }
+                                                            }
+                                                            }
                                                         }
                                                     }
-                                                    td { "{ability.ability_name}" }
-                                                    td { class: "num", "{format_number(ability.total_value)}" }
-                                                    td { class: "num pct-cell",
-                                                        span { class: "pct-bar", style: "width: {ability.percent_of_total.min(100.0)}%;" }
-                                                        span { class: "pct-text", "{format_pct(ability.percent_of_total)}" }
                                                     }
-                                                    td { class: "num", "{format_number(ability.dps)}" }
-                                                    td { class: "num", "{ability.hit_count}" }
-                                                    td { class: "num", "{format_number(ability.avg_hit)}" }
-                                                    td { class: "num", "{format_pct(ability.crit_rate)}" }
                                                 }
                                             }
                                         }
                                     }
+
+                                    if let Some(ability_name) = selected_ability.read().clone() {
+                                        AbilityHistogramPanel {
+                                            ability_name: ability_name,
+                                            histogram: histogram.read().clone(),
+                                            loading: *histogram_loading.read(),
+                                        }
+                                    }
+                                }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Ability drill-down histogram
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Props, Clone, PartialEq)]
+struct AbilityHistogramPanelProps {
+    ability_name: String,
+    histogram: Vec<HistoPoint>,
+    loading: bool,
+}
+
+/// Per-hit magnitude distribution for the clicked ability row, drawn as bars
+/// similar to the ability table's `pct-bar` spans. Each bucket's bar is split
+/// into a normal-hit portion and a crit portion so a crit cluster separated
+/// from the normal-hit cluster is visible at a glance.
+#[component]
+fn AbilityHistogramPanel(props: AbilityHistogramPanelProps) -> Element {
+    let max_count = props.histogram.iter().map(|p| p.count).max().unwrap_or(0).max(1);
+
+    rsx! {
+        div { class: "ability-histogram-panel",
+            div { class: "ability-histogram-header",
+                span { class: "ability-histogram-title", "Hit distribution: {props.ability_name}" }
+            }
+            if props.loading {
+                div { class: "ability-histogram-loading", "Loading…" }
+            } else if props.histogram.is_empty() {
+                div { class: "ability-histogram-empty", "No per-hit data for this ability in the current range." }
+            } else {
+                div { class: "ability-histogram-bars",
+                    for point in props.histogram.iter() {
+                        {
+                            let normal_count = point.count.saturating_sub(point.crit_count);
+                            let normal_pct = normal_count as f64 / max_count as f64 * 100.0;
+                            let crit_pct = point.crit_count as f64 / max_count as f64 * 100.0;
+                            rsx! {
+                                div { class: "histogram-bucket",
+                                    div { class: "histogram-bar",
+                                        span { class: "histogram-bar-normal", style: "height: {normal_pct}%;" }
+                                        span { class: "histogram-bar-crit", style: "height: {crit_pct}%;" }
+                                    }
+                                    span { class: "histogram-bucket-label",
+                                        "{format_number(point.bucket_lower)}–{format_number(point.bucket_upper)}"
+                                    }
+                                    span { class: "histogram-bucket-count", "{point.count}" }
                                 }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Entity timeline heatmap
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Props, Clone, PartialEq)]
+struct EntityHeatmapPanelProps {
+    rows: Vec<HeatmapRow>,
+    loading: bool,
+    rate_label: String,
+}
+
+/// One row per source/target entity, one column per equal-width time bucket,
+/// with each cell's background opacity driven by `HeatmapCell::intensity`
+/// (already normalized 0-1 across the whole grid by the backend query) so
+/// burst windows and downtime jump out without reading numbers.
+#[component]
+fn EntityHeatmapPanel(props: EntityHeatmapPanelProps) -> Element {
+    rsx! {
+        div { class: "entity-heatmap",
+            if props.loading {
+                div { class: "entity-heatmap-loading", "Loading…" }
+            } else if props.rows.is_empty() {
+                div { class: "entity-heatmap-empty", "No data for the heatmap view." }
+            } else {
+                for row in props.rows.iter() {
+                    div { class: "heatmap-row", key: "{row.entity_name}",
+                        span { class: "heatmap-row-label", "{row.entity_name}" }
+                        div { class: "heatmap-cells",
+                            for cell in row.cells.iter() {
+                                span {
+                                    class: "heatmap-cell",
+                                    style: "background-color: rgba(255, 80, 0, {cell.intensity});",
+                                    title: "{format_number(cell.rate)} {props.rate_label}",
                                 }
                             }
                         }
@@ -1,4 +1,5 @@
 use crate::commands;
+use crate::scheduler::{PrecacheSink, Scheduler};
 use baras_core::app_state::AppState;
 use baras_core::directory_watcher::{self as core_watcher, DirectoryEvent, DirectoryWatcher};
 use std::path::PathBuf;
@@ -6,8 +7,11 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 
+/// Worker tasks the scheduler spawns to drain the Normal/Low queues.
+const SCHEDULER_WORKERS: usize = 2;
+
 /// Initialize the file index and start the watcher
-pub async fn init_watcher(state: Arc<RwLock<AppState>>) -> Option<JoinHandle<()>> {
+pub async fn init_watcher(state: Arc<RwLock<AppState>>, precache_sink: PrecacheSink) -> Option<JoinHandle<()>> {
     let dir = {
         let s = state.read().await;
         PathBuf::from(&s.config.log_directory)
@@ -18,6 +22,13 @@ pub async fn init_watcher(state: Arc<RwLock<AppState>>) -> Option<JoinHandle<()>
         return None;
     }
 
+    let scheduler = Scheduler::new(
+        SCHEDULER_WORKERS,
+        Arc::clone(&state),
+        Arc::new(|app_state| app_state.precache_meter_entries()),
+        precache_sink,
+    );
+
     // Build initial index using core
     match core_watcher::build_index(&dir) {
         Ok((index, newest)) => {
@@ -32,8 +43,7 @@ pub async fn init_watcher(state: Arc<RwLock<AppState>>) -> Option<JoinHandle<()>
 
             // Auto-load newest file if available
             if let Some(newest_path) = newest {
-                let path_str = newest_path.to_string_lossy().to_string();
-                commands::parse_file(&path_str, Arc::clone(&state)).await;
+                scheduler.enqueue_full_parse(newest_path).await;
             }
         }
         Err(e) => {
@@ -56,18 +66,30 @@ pub async fn init_watcher(state: Arc<RwLock<AppState>>) -> Option<JoinHandle<()>
     let watcher_state = Arc::clone(&state);
     let handle = tokio::spawn(async move {
         while let Some(event) = watcher.next_event().await {
-            handle_watcher_event(event, Arc::clone(&watcher_state)).await;
+            handle_watcher_event(event, Arc::clone(&watcher_state), &scheduler).await;
         }
     });
 
     Some(handle)
 }
 
-async fn handle_watcher_event(event: DirectoryEvent, state: Arc<RwLock<AppState>>) {
+async fn handle_watcher_event(event: DirectoryEvent, state: Arc<RwLock<AppState>>, scheduler: &Scheduler) {
     match event {
         DirectoryEvent::NewFile(path) => {
             println!("New log file detected: {}", path.display());
 
+            // A newer file supersedes whatever the previous newest file was
+            // tailing/parsing, so that work is dropped/aborted instead of
+            // racing this one to write `CombatEvent`s into `AppState`.
+            {
+                let s = state.read().await;
+                if let Some(previous) = s.file_index.as_ref().and_then(|index| index.newest_path()) {
+                    if previous != path {
+                        scheduler.supersede(&previous);
+                    }
+                }
+            }
+
             // Add to index
             {
                 let mut s = state.write().await;
@@ -76,12 +98,17 @@ async fn handle_watcher_event(event: DirectoryEvent, state: Arc<RwLock<AppState>
                 }
             }
 
-            // Parse and tail the new file
-            let path_str = path.to_string_lossy().to_string();
-            commands::parse_file(&path_str, state).await;
+            // Enqueue instead of awaiting: a large file or a burst of
+            // NewFile events no longer blocks tailing or overlay updates.
+            scheduler.enqueue_full_parse(path.clone()).await;
+            scheduler.enqueue_precache(path).await;
         }
 
         DirectoryEvent::FileRemoved(path) => {
+            // Cancel any queued/running job for this path before dropping
+            // it from the index.
+            scheduler.supersede(&path);
+
             let mut s = state.write().await;
             if let Some(index) = &mut s.file_index {
                 index.remove_file(&path);
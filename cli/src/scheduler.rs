@@ -0,0 +1,232 @@
+//! Priority task scheduler for parsing and stat aggregation
+//!
+//! `init_watcher`/`handle_watcher_event` used to call `commands::parse_file`
+//! inline on the watcher task, so a large file reparse or a burst of
+//! `NewFile` events blocked overlay updates. [`Scheduler`] owns two bounded
+//! queues by priority -- `Normal` for full-file parses, `Low` for background
+//! per-`Entity` aggregate precomputation -- and runs them across a small
+//! pool of worker tasks that always drain `Normal` before `Low`.
+//!
+//! There used to be a third, `High` lane reserved for incremental live-tail
+//! appends feeding the `MeterOverlay` directly, but this crate has no
+//! incremental/resumable reader to feed it - every `DirectoryEvent` this
+//! scheduler ever receives is `NewFile`/`FileRemoved`, nothing that
+//! represents "more lines appended to the file I'm already tailing" - so
+//! that lane was removed rather than kept around unreachable.
+//!
+//! Every enqueued job is tracked by a `u64` task id and a per-path
+//! generation counter, so when a newer log file supersedes an older one (or
+//! the older file is removed), the older file's in-flight and still-queued
+//! jobs are dropped/aborted instead of racing to write stale `CombatEvent`s
+//! into `AppState`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_channel::{bounded, Receiver, Sender};
+use baras_core::app_state::AppState;
+use overlay::MeterEntry;
+use tokio::sync::RwLock;
+use tokio::task::AbortHandle;
+
+use crate::commands;
+
+/// Depth of each priority queue before `enqueue` starts blocking the
+/// caller (backpressure instead of unbounded growth during a burst).
+const QUEUE_CAPACITY: usize = 64;
+
+/// Relative priority of an enqueued job. Workers always drain `Normal`
+/// before `Low`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Priority {
+    /// Full-file (re)parses, e.g. the first parse of a newly detected log.
+    Normal,
+    /// Background per-`Entity` DPS/HPS/threat rollups.
+    Low,
+}
+
+/// Work a worker task can execute, carrying enough context (`path`,
+/// `generation`) to answer "is this still the job I think it is" at the
+/// moment it's dequeued.
+struct Job {
+    id: u64,
+    path: PathBuf,
+    generation: u64,
+    kind: JobKind,
+}
+
+enum JobKind {
+    /// Parse `path` in full, e.g. on first detection of a log file.
+    FullParse,
+    /// Recompute rolled-up per-`Entity` DPS/HPS/threat off the hot path via
+    /// the scheduler's `precache_fn` and publish the result to
+    /// `precache_sink`.
+    Precache,
+}
+
+/// Computes the `MeterOverlay`'s next frame of entries from `AppState`,
+/// supplied by the scheduler's owner rather than hardcoded here so this
+/// module doesn't need to know `AppState`'s internals.
+pub type PrecacheFn = Arc<dyn Fn(&AppState) -> Vec<MeterEntry> + Send + Sync>;
+
+/// Latest completed `Precache` result, polled by the overlay's render loop.
+pub type PrecacheSink = Arc<Mutex<Option<Vec<MeterEntry>>>>;
+
+/// Priority task scheduler owning the two bounded queues and the
+/// generation/abort bookkeeping that lets a newer (or removed) log file's
+/// jobs preempt an older file's.
+#[derive(Clone)]
+pub struct Scheduler {
+    normal_tx: Sender<Job>,
+    low_tx: Sender<Job>,
+    next_id: Arc<AtomicU64>,
+    /// Bumped by `supersede`; a job's captured `generation` going stale
+    /// relative to this is what makes a still-queued job a silent no-op.
+    generations: Arc<Mutex<HashMap<PathBuf, u64>>>,
+    /// Task id -> (path, abort handle) for every job currently executing,
+    /// so `supersede` can abort whichever one belongs to a given path.
+    inflight: Arc<Mutex<HashMap<u64, (PathBuf, AbortHandle)>>>,
+    /// Paths with a `FullParse` already queued (not yet started), so a burst
+    /// of repeated `NewFile` events for the same path only enqueues once.
+    full_parse_queued: Arc<Mutex<HashSet<PathBuf>>>,
+}
+
+impl Scheduler {
+    /// Spawn `worker_count` worker tasks draining the two priority queues.
+    pub fn new(worker_count: usize, state: Arc<RwLock<AppState>>, precache_fn: PrecacheFn, precache_sink: PrecacheSink) -> Self {
+        let (normal_tx, normal_rx) = bounded(QUEUE_CAPACITY);
+        let (low_tx, low_rx) = bounded(QUEUE_CAPACITY);
+
+        let generations = Arc::new(Mutex::new(HashMap::new()));
+        let inflight = Arc::new(Mutex::new(HashMap::new()));
+        let full_parse_queued = Arc::new(Mutex::new(HashSet::new()));
+
+        for _ in 0..worker_count.max(1) {
+            tokio::spawn(Self::run_worker(
+                normal_rx.clone(),
+                low_rx.clone(),
+                Arc::clone(&state),
+                Arc::clone(&precache_fn),
+                Arc::clone(&precache_sink),
+                Arc::clone(&generations),
+                Arc::clone(&inflight),
+                Arc::clone(&full_parse_queued),
+            ));
+        }
+
+        Self {
+            normal_tx,
+            low_tx,
+            next_id: Arc::new(AtomicU64::new(0)),
+            generations,
+            inflight,
+            full_parse_queued,
+        }
+    }
+
+    /// Enqueue a `FullParse` job for `path`, `Normal` priority, deduping
+    /// repeated `NewFile` events for a path that's already queued.
+    pub async fn enqueue_full_parse(&self, path: PathBuf) {
+        if !self.full_parse_queued.lock().unwrap().insert(path.clone()) {
+            return;
+        }
+        self.enqueue(Priority::Normal, path, JobKind::FullParse).await;
+    }
+
+    /// Enqueue a `Precache` job for `path`, `Low` priority.
+    pub async fn enqueue_precache(&self, path: PathBuf) {
+        self.enqueue(Priority::Low, path, JobKind::Precache).await;
+    }
+
+    /// Mark every job queued or running for `path` stale: still-queued jobs
+    /// captured the pre-bump generation so they silently no-op once
+    /// dequeued, and whichever job is currently running for `path` (if any)
+    /// is aborted immediately. Called both when a newer log file is
+    /// auto-loaded and when `path` itself is removed.
+    pub fn supersede(&self, path: &Path) {
+        *self.generations.lock().unwrap().entry(path.to_path_buf()).or_insert(0) += 1;
+
+        let mut inflight = self.inflight.lock().unwrap();
+        inflight.retain(|_, (job_path, handle)| {
+            if job_path == path {
+                handle.abort();
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    async fn enqueue(&self, priority: Priority, path: PathBuf, kind: JobKind) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let generation = *self.generations.lock().unwrap().entry(path.clone()).or_insert(0);
+        let job = Job { id, path, generation, kind };
+
+        let tx = match priority {
+            Priority::Normal => &self.normal_tx,
+            Priority::Low => &self.low_tx,
+        };
+        let _ = tx.send(job).await;
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn run_worker(
+        normal_rx: Receiver<Job>,
+        low_rx: Receiver<Job>,
+        state: Arc<RwLock<AppState>>,
+        precache_fn: PrecacheFn,
+        precache_sink: PrecacheSink,
+        generations: Arc<Mutex<HashMap<PathBuf, u64>>>,
+        inflight: Arc<Mutex<HashMap<u64, (PathBuf, AbortHandle)>>>,
+        full_parse_queued: Arc<Mutex<HashSet<PathBuf>>>,
+    ) {
+        loop {
+            let job = tokio::select! {
+                biased;
+                Ok(job) = normal_rx.recv() => job,
+                Ok(job) = low_rx.recv() => job,
+                else => break,
+            };
+
+            if matches!(job.kind, JobKind::FullParse) {
+                full_parse_queued.lock().unwrap().remove(&job.path);
+            }
+
+            // Dropped while queued: a newer file superseded (or removed)
+            // this job's path before a worker got to it.
+            let current_generation = *generations.lock().unwrap().get(&job.path).unwrap_or(&0);
+            if job.generation != current_generation {
+                continue;
+            }
+
+            let id = job.id;
+            let path = job.path.clone();
+            let state = Arc::clone(&state);
+            let precache_fn = Arc::clone(&precache_fn);
+            let precache_sink = Arc::clone(&precache_sink);
+
+            let handle = tokio::spawn(Self::run_job(job, state, precache_fn, precache_sink));
+            inflight.lock().unwrap().insert(id, (path, handle.abort_handle()));
+            let _ = handle.await;
+            inflight.lock().unwrap().remove(&id);
+        }
+    }
+
+    async fn run_job(job: Job, state: Arc<RwLock<AppState>>, precache_fn: PrecacheFn, precache_sink: PrecacheSink) {
+        match job.kind {
+            JobKind::FullParse => {
+                let path_str = job.path.to_string_lossy().to_string();
+                commands::parse_file(&path_str, state).await;
+            }
+            JobKind::Precache => {
+                let entries = {
+                    let guard = state.read().await;
+                    precache_fn(&guard)
+                };
+                *precache_sink.lock().unwrap() = Some(entries);
+            }
+        }
+    }
+}
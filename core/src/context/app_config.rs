@@ -0,0 +1,247 @@
+//! Application configuration
+//!
+//! Loaded once at startup (and hot-reloaded by [`super::ConfigWatcher`]) from
+//! a TOML file on disk. Only the fields other modules already depend on are
+//! modeled here; richer sections get filled in as features need them.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn default_log_directory() -> String {
+    dirs_fallback_home()
+        .join("Documents/Star Wars - The Old Republic/CombatLogs")
+        .to_string_lossy()
+        .to_string()
+}
+
+fn dirs_fallback_home() -> PathBuf {
+    std::env::var_os("HOME").map(PathBuf::from).unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Current on-disk schema version. Bump this and add a [`Migration`] to
+/// [`migrations`] whenever `AppConfig`'s shape changes.
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    pub version: u32,
+    pub log_directory: String,
+    /// When true, the directory watcher automatically starts tailing the
+    /// most recently created non-empty log under `log_directory` once it
+    /// appears, so the overlay works without the player picking a file.
+    pub auto_tail: bool,
+    pub debug: DebugConfig,
+    pub relay: RelayConfig,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_CONFIG_VERSION,
+            log_directory: default_log_directory(),
+            auto_tail: true,
+            debug: DebugConfig::default(),
+            relay: RelayConfig::default(),
+        }
+    }
+}
+
+/// Network relay that fans out live combat updates to external subscribers
+/// (browser overlays, OBS/stream widgets, a remote raid-lead dashboard) over
+/// TCP, newline-delimited JSON. Disabled by default since it opens a socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelayConfig {
+    pub enabled: bool,
+    pub bind_address: String,
+    /// If set, a connecting client's first line must echo this value before
+    /// it's subscribed to the feed.
+    pub shared_secret: Option<String>,
+}
+
+impl Default for RelayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind_address: "127.0.0.1:7834".to_string(),
+            shared_secret: None,
+        }
+    }
+}
+
+/// One step in the migration chain: brings a raw config table saved at
+/// `version` up to the next version. Runs on the untyped TOML table rather
+/// than `AppConfig` itself, since older files may not deserialize cleanly
+/// into the current struct shape - that's the whole reason a migration is
+/// needed.
+struct Migration {
+    version: u32,
+    apply: fn(&mut toml::value::Table),
+}
+
+/// Ordered oldest-to-newest. Each step assumes the table is at exactly
+/// `version` and leaves it one step closer to [`CURRENT_CONFIG_VERSION`].
+fn migrations() -> Vec<Migration> {
+    vec![Migration {
+        version: 1,
+        apply: |table| {
+            // v1 called this field `logDirectory`; renamed to match the
+            // snake_case convention the rest of the struct uses.
+            if let Some(old) = table.remove("logDirectory") {
+                table.entry("log_directory".to_string()).or_insert(old);
+            }
+        },
+    }]
+}
+
+/// Mirrors Alacritty's `debug` config group: a way to get a reproducible
+/// trace out of a release build without recompiling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugConfig {
+    pub log_level: LogLevel,
+    /// When set, log output is additionally written to this file.
+    pub log_file: Option<PathBuf>,
+    /// When true, log every `DirectoryEvent` and overlay
+    /// `OverlayUpdate`/`OverlayCommand` with a timestamp.
+    pub print_events: bool,
+}
+
+impl Default for DebugConfig {
+    fn default() -> Self {
+        Self {
+            log_level: LogLevel::Info,
+            log_file: None,
+            print_events: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// The `tracing`/`EnvFilter` directive for this level.
+    pub fn as_filter(&self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+/// Global flag mirroring `debug.print_events`, checked by the directory
+/// watcher and overlay bridge so they don't need a config handle threaded
+/// through every call site.
+static PRINT_EVENTS: AtomicBool = AtomicBool::new(false);
+
+pub fn set_print_events(enabled: bool) {
+    PRINT_EVENTS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn print_events_enabled() -> bool {
+    PRINT_EVENTS.load(Ordering::Relaxed)
+}
+
+impl AppConfig {
+    /// Default config file path, `$XDG_CONFIG_HOME/baras/config.toml`
+    /// (falling back to `~/.config/baras/config.toml`).
+    pub fn config_path() -> PathBuf {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| dirs_fallback_home().join(".config"));
+        config_home.join("baras").join("config.toml")
+    }
+
+    /// Load from the default config path, falling back to defaults if the
+    /// file doesn't exist or fails to parse.
+    pub fn load() -> Self {
+        Self::load_from(&Self::config_path()).unwrap_or_default()
+    }
+
+    /// Load and parse the config file at `path`, running any migrations
+    /// needed to bring it up to [`CURRENT_CONFIG_VERSION`] and writing the
+    /// upgraded config back through `confy` so the rewrite isn't repeated on
+    /// every startup.
+    pub fn load_from(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let mut doc: toml::Value = toml::from_str(&contents).map_err(|e| e.to_string())?;
+
+        let mut version = doc.get("version").and_then(|v| v.as_integer()).unwrap_or(1) as u32;
+        let mut migrated = false;
+        for migration in migrations() {
+            if version == migration.version {
+                if let Some(table) = doc.as_table_mut() {
+                    tracing::info!(from_version = migration.version, path = %path.display(), "applying app-config migration");
+                    (migration.apply)(table);
+                    version += 1;
+                    table.insert("version".to_string(), toml::Value::Integer(version as i64));
+                    migrated = true;
+                }
+            }
+        }
+
+        let config: AppConfig = doc.try_into().map_err(|e: toml::de::Error| e.to_string())?;
+        set_print_events(config.debug.print_events);
+
+        if migrated {
+            if let Err(e) = config.persist_to(path) {
+                tracing::warn!(error = %e, path = %path.display(), "failed to persist migrated app config");
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Persist to `path` via `confy`, which writes atomically (temp file +
+    /// rename) so a crash mid-write can't corrupt the config.
+    pub fn persist_to(&self, path: &Path) -> Result<(), String> {
+        confy::store_path(path, self).map_err(|e| e.to_string())
+    }
+
+    /// Persist to the default config path, writing `version` as it is set on `self`.
+    pub fn persist(&self) -> Result<(), String> {
+        self.persist_to(&Self::config_path())
+    }
+
+    /// Initialize the global `tracing` subscriber from `self.debug`.
+    ///
+    /// Safe to call once at startup. An optional rotating file sink is
+    /// layered in alongside stdout when `debug.log_file` is set.
+    pub fn init_tracing(&self) {
+        use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+        set_print_events(self.debug.print_events);
+
+        let filter = EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new(self.debug.log_level.as_filter()));
+
+        let registry = tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt::layer());
+
+        if let Some(path) = &self.debug.log_file {
+            let dir = path.parent().unwrap_or_else(|| Path::new("."));
+            let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("baras.log");
+            let file_appender = tracing_appender::rolling::daily(dir, file_name);
+            registry.with(fmt::layer().with_writer(file_appender).with_ansi(false)).init();
+        } else {
+            registry.init();
+        }
+    }
+}
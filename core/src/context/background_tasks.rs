@@ -0,0 +1,194 @@
+//! Background worker registry
+//!
+//! Tracks every long-lived task (the overlay thread, live encounter
+//! parsing, parquet writers) behind one inspectable registry instead of
+//! ad-hoc `Mutex<...State>` bookkeeping per feature. Workers report their
+//! own liveness via `step()`, so a dead worker surfaces as
+//! `WorkerState::Dead` instead of silently stalling.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Liveness reported by a worker's `step()` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerState {
+    /// Did useful work this step.
+    Active,
+    /// Step ran but had nothing to do (e.g. waiting for input).
+    Idle,
+    /// The worker has stopped permanently and should be restarted.
+    Dead,
+}
+
+/// A long-lived background task tracked by [`BackgroundTasks`].
+pub trait Worker: Send {
+    fn name(&self) -> &str;
+
+    /// Run one unit of work and report liveness. Called in a tight loop on
+    /// the worker's dedicated thread until it returns `WorkerState::Dead`.
+    fn step(&mut self) -> WorkerState;
+}
+
+/// Error reported by a worker, tagged with the worker's name.
+#[derive(Debug, Clone)]
+pub struct WorkerError {
+    pub worker: String,
+    pub message: String,
+}
+
+/// Point-in-time status for a registered worker.
+#[derive(Debug, Clone)]
+pub struct WorkerStatus {
+    pub name: String,
+    pub state: WorkerState,
+    pub last_error: Option<String>,
+    pub iterations: u64,
+}
+
+type WorkerFactory = dyn Fn() -> Box<dyn Worker> + Send;
+
+struct WorkerEntry {
+    status: Arc<Mutex<WorkerStatus>>,
+    handle: Option<JoinHandle<()>>,
+    factory: Box<WorkerFactory>,
+    /// Checked between `step()` calls on the worker's thread; `restart()`
+    /// sets this before joining so a live (`Active`/`Idle`) worker actually
+    /// stops instead of being joined forever.
+    stop: Arc<AtomicBool>,
+}
+
+/// Central registry of every long-lived background task.
+#[derive(Clone)]
+pub struct BackgroundTasks {
+    workers: Arc<Mutex<HashMap<String, WorkerEntry>>>,
+    error_tx: Sender<WorkerError>,
+    error_rx: Arc<Mutex<Receiver<WorkerError>>>,
+}
+
+impl Default for BackgroundTasks {
+    fn default() -> Self {
+        let (error_tx, error_rx) = mpsc::channel();
+        Self {
+            workers: Arc::new(Mutex::new(HashMap::new())),
+            error_tx,
+            error_rx: Arc::new(Mutex::new(error_rx)),
+        }
+    }
+}
+
+impl BackgroundTasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register and start a worker, remembering how to respawn it via
+    /// `restart`.
+    pub fn spawn<F, W>(&self, make_worker: F)
+    where
+        F: Fn() -> W + Send + 'static,
+        W: Worker + 'static,
+    {
+        let worker = make_worker();
+        let name = worker.name().to_string();
+
+        let status = Arc::new(Mutex::new(WorkerStatus {
+            name: name.clone(),
+            state: WorkerState::Idle,
+            last_error: None,
+            iterations: 0,
+        }));
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = self.run(Box::new(worker), status.clone(), stop.clone());
+        let factory: Box<WorkerFactory> = Box::new(move || Box::new(make_worker()));
+
+        let mut workers = self.workers.lock().unwrap();
+        workers.insert(
+            name,
+            WorkerEntry {
+                status,
+                handle: Some(handle),
+                factory,
+                stop,
+            },
+        );
+    }
+
+    fn run(&self, mut worker: Box<dyn Worker>, status: Arc<Mutex<WorkerStatus>>, stop: Arc<AtomicBool>) -> JoinHandle<()> {
+        let error_tx = self.error_tx.clone();
+        thread::spawn(move || loop {
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+
+            let state = worker.step();
+
+            let mut s = status.lock().unwrap();
+            s.state = state;
+            s.iterations += 1;
+
+            if state == WorkerState::Dead {
+                s.last_error = Some("worker exited".to_string());
+                let _ = error_tx.send(WorkerError {
+                    worker: s.name.clone(),
+                    message: "worker exited".to_string(),
+                });
+                break;
+            }
+
+            let idle = state == WorkerState::Idle;
+            drop(s);
+
+            if idle {
+                thread::sleep(Duration::from_millis(10));
+            }
+        })
+    }
+
+    /// Snapshot the status of every registered worker.
+    pub fn list(&self) -> Vec<WorkerStatus> {
+        let workers = self.workers.lock().unwrap();
+        workers.values().map(|w| w.status.lock().unwrap().clone()).collect()
+    }
+
+    /// Restart a worker by name: signal its old thread to stop, join it, and
+    /// spawn a fresh instance from its remembered factory. Returns `false`
+    /// if no worker is registered under `name`.
+    pub fn restart(&self, name: &str) -> bool {
+        let mut workers = self.workers.lock().unwrap();
+        let Some(entry) = workers.get_mut(name) else {
+            return false;
+        };
+
+        // Signal before joining - the worker only exits its loop once this
+        // is observed between `step()` calls, otherwise a live worker would
+        // never reach `WorkerState::Dead` and the join below would hang.
+        entry.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = entry.handle.take() {
+            let _ = handle.join();
+        }
+
+        {
+            let mut status = entry.status.lock().unwrap();
+            status.state = WorkerState::Idle;
+            status.last_error = None;
+            status.iterations = 0;
+        }
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker = (entry.factory)();
+        entry.handle = Some(self.run(worker, entry.status.clone(), stop.clone()));
+        entry.stop = stop;
+        true
+    }
+
+    /// Drain any errors reported since the last call.
+    pub fn drain_errors(&self) -> Vec<WorkerError> {
+        let rx = self.error_rx.lock().unwrap();
+        rx.try_iter().collect()
+    }
+}
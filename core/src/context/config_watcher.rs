@@ -0,0 +1,101 @@
+use crate::context::AppConfig;
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, Receiver};
+use tokio::time::Instant;
+
+/// Debounce window for coalescing rapid successive writes to the config file
+/// (editors often emit several modify events per save).
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+pub enum ConfigEvent {
+    /// The config file was re-parsed successfully; carries the new config.
+    Reloaded(AppConfig),
+    /// The config file changed but failed to parse; no new config is
+    /// produced.
+    Error(String),
+}
+
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+    path: PathBuf,
+}
+
+impl ConfigWatcher {
+    pub fn new(path: &Path) -> notify::Result<Self> {
+        let (tx, rx) = mpsc::channel(100);
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                if tx.try_send(res).is_err() {
+                    tracing::error!(
+                        "Config watcher channel full, filesystem event dropped - this should not happen"
+                    );
+                }
+            },
+            Config::default(),
+        )?;
+
+        // Watch the parent directory rather than the file itself so we keep
+        // receiving events across editors that replace the file on save.
+        let watch_target = path.parent().unwrap_or(path);
+        watcher.watch(watch_target, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Wait for the next debounced config reload, re-parsing the file once
+    /// writes have settled for [`DEBOUNCE`].
+    pub async fn next_event(&mut self) -> Option<ConfigEvent> {
+        loop {
+            let event_result = self.rx.recv().await?;
+
+            let event = match event_result {
+                Ok(event) => event,
+                Err(e) => {
+                    return Some(ConfigEvent::Error(format!("Config watcher error: {}", e)));
+                }
+            };
+
+            if !self.touches_config(&event) {
+                continue;
+            }
+
+            // Coalesce any further events within the debounce window before
+            // re-parsing, so a burst of writes from one save only reloads once.
+            let deadline = Instant::now() + DEBOUNCE;
+            loop {
+                match tokio::time::timeout_at(deadline, self.rx.recv()).await {
+                    Ok(Some(Ok(event))) if self.touches_config(&event) => continue,
+                    Ok(Some(Ok(_))) => continue,
+                    Ok(Some(Err(_))) | Ok(None) => break,
+                    Err(_) => break, // debounce window elapsed
+                }
+            }
+
+            return Some(self.reload());
+        }
+    }
+
+    fn touches_config(&self, event: &Event) -> bool {
+        matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_))
+            && event.paths.iter().any(|p| p == &self.path)
+    }
+
+    fn reload(&self) -> ConfigEvent {
+        match AppConfig::load_from(&self.path) {
+            Ok(config) => ConfigEvent::Reloaded(config),
+            Err(e) => ConfigEvent::Error(format!(
+                "Failed to reload config at {}: {}",
+                self.path.display(),
+                e
+            )),
+        }
+    }
+}
@@ -1,10 +1,15 @@
-use lasso::{Spur, ThreadedRodeo};
+use lasso::{Rodeo, Spur, ThreadedRodeo};
 use std::sync::OnceLock;
 
 /// Interned string key - 4 bytes instead of 24 for String.
 pub type IStr = Spur;
 
 /// Global string interner for combat log data.
+///
+/// This arena never releases strings - fine for short sessions, but a slow
+/// leak across a multi-hour live raid night. Long-lived callers that want
+/// their strings reclaimed when an encounter ends should use
+/// [`EncounterInterner`] instead.
 static INTERNER: OnceLock<ThreadedRodeo> = OnceLock::new();
 
 /// Get the global interner (initializes on first call).
@@ -12,12 +17,71 @@ pub fn interner() -> &'static ThreadedRodeo {
     INTERNER.get_or_init(ThreadedRodeo::default)
 }
 
-/// Intern a string, returning a key.
+/// Intern a string in the global arena, returning a key.
 pub fn intern(s: &str) -> IStr {
     interner().get_or_intern(s)
 }
 
-/// Resolve an interned key back to a string.
+/// Resolve a key interned in the global arena back to a string.
 pub fn resolve(key: IStr) -> &'static str {
     interner().resolve(&key)
 }
+
+/// Entry/byte counts for an interner, so long live sessions can surface how
+/// much string data has piled up.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+pub struct InternerStats {
+    pub entries: usize,
+    pub bytes: usize,
+}
+
+/// Snapshot the global interner's size.
+pub fn interner_stats() -> InternerStats {
+    let interner = interner();
+    InternerStats {
+        entries: interner.len(),
+        bytes: interner.iter().map(|(_, s)| s.len()).sum(),
+    }
+}
+
+/// Per-encounter string interner.
+///
+/// Unlike the global [`ThreadedRodeo`], this arena is owned by a single
+/// encounter and dropped with it, so its strings don't accumulate for the
+/// lifetime of the process. Because the arena isn't `'static`, [`EncounterInterner::resolve`]
+/// hands back an owned `Box<str>` instead of a borrowed `&'static str` -
+/// safe to hand to a parquet writer after the encounter (and this interner)
+/// goes away.
+pub struct EncounterInterner {
+    rodeo: Rodeo,
+}
+
+impl EncounterInterner {
+    pub fn new() -> Self {
+        Self { rodeo: Rodeo::new() }
+    }
+
+    /// Intern a string in this encounter's arena, returning a key.
+    pub fn intern(&mut self, s: &str) -> IStr {
+        self.rodeo.get_or_intern(s)
+    }
+
+    /// Resolve a key interned in this arena back to an owned string.
+    pub fn resolve(&self, key: IStr) -> Box<str> {
+        Box::from(self.rodeo.resolve(&key))
+    }
+
+    /// Snapshot this encounter's interner size.
+    pub fn stats(&self) -> InternerStats {
+        InternerStats {
+            entries: self.rodeo.len(),
+            bytes: self.rodeo.iter().map(|(_, s)| s.len()).sum(),
+        }
+    }
+}
+
+impl Default for EncounterInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
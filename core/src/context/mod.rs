@@ -1,11 +1,19 @@
 mod app_config;
 mod background_tasks;
+mod config_watcher;
 mod directory_index;
 mod interner;
 mod parsing_session;
+mod saved_session;
+mod triggers;
+mod watcher;
 
-pub use app_config::AppConfig;
-pub use background_tasks::BackgroundTasks;
+pub use app_config::{AppConfig, CURRENT_CONFIG_VERSION};
+pub use background_tasks::{BackgroundTasks, Worker, WorkerError, WorkerState, WorkerStatus};
+pub use config_watcher::{ConfigEvent, ConfigWatcher};
 pub use directory_index::DirectoryIndex;
-pub use interner::{intern, resolve, IStr};
+pub use interner::{intern, interner_stats, resolve, EncounterInterner, IStr, InternerStats};
 pub use parsing_session::{resolve_log_path, ParsingSession};
+pub use saved_session::{SavedSession, CURRENT_SCHEMA_VERSION};
+pub use triggers::{ComparisonOp, CounterCondition, CounterRegistry, TriggerDefinition, TriggerEngine, TriggerFired};
+pub use watcher::{build_index, DirectoryEvent, DirectoryWatcher};
@@ -0,0 +1,110 @@
+//! Saved parse sessions
+//!
+//! Lets a user save a fully parsed encounter (the entity list, ability
+//! breakdowns, and raid overview rows the data explorer renders) to disk and
+//! reopen it later without re-parsing the combat log. Each file carries a
+//! `schema_version`; on load, an ordered chain of migrations is run over the
+//! raw document to bring older files up to [`CURRENT_SCHEMA_VERSION`] before
+//! it's deserialized into [`SavedSession`], modeled on the migration-class
+//! pattern used by tabletop VTT world migrations.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::query::{AbilityBreakdown, EntityBreakdown, RaidOverviewRow};
+
+/// Current on-disk schema version. Bump this and add a [`Migration`]
+/// whenever `SavedSession`'s shape changes.
+pub const CURRENT_SCHEMA_VERSION: &str = "3";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub schema_version: String,
+    pub encounter_name: String,
+    pub entities: Vec<EntityBreakdown>,
+    pub abilities: Vec<AbilityBreakdown>,
+    pub overview: Vec<RaidOverviewRow>,
+}
+
+/// The raw on-disk document a migration transforms. Older schema versions
+/// may not deserialize cleanly into the current `SavedSession` struct, so
+/// migrations operate on the untyped document instead.
+type SessionDoc = serde_json::Value;
+
+/// One step in the migration chain: brings a document saved at `version` up
+/// to the next version.
+struct Migration {
+    version: &'static str,
+    apply: fn(&mut SessionDoc),
+}
+
+/// Ordered oldest-to-newest. Each step assumes the document is at exactly
+/// `version` and leaves it one step closer to [`CURRENT_SCHEMA_VERSION`].
+fn migrations() -> Vec<Migration> {
+    vec![
+        Migration {
+            version: "1",
+            apply: |doc| {
+                // v1 predates `target_first_hit_secs`; backfill it as absent
+                // rather than failing to deserialize.
+                if let Some(abilities) = doc.get_mut("abilities").and_then(|v| v.as_array_mut()) {
+                    for ability in abilities {
+                        if let Some(obj) = ability.as_object_mut() {
+                            obj.entry("target_first_hit_secs").or_insert(serde_json::Value::Null);
+                        }
+                    }
+                }
+            },
+        },
+        Migration {
+            version: "2",
+            apply: |doc| {
+                // v2 called the raid overview rows `raid_rows`; renamed to
+                // `overview` to match the query API's naming.
+                if let Some(obj) = doc.as_object_mut() {
+                    if let Some(rows) = obj.remove("raid_rows") {
+                        obj.insert("overview".to_string(), rows);
+                    }
+                }
+            },
+        },
+    ]
+}
+
+fn next_version(version: &str) -> String {
+    version.parse::<u32>().map(|n| (n + 1).to_string()).unwrap_or_else(|_| version.to_string())
+}
+
+impl SavedSession {
+    /// Serialize and write to `path`, with `schema_version` as it is set on `self`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    /// Load a session file, running any migrations needed to bring it up to
+    /// [`CURRENT_SCHEMA_VERSION`], logging each one applied.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let mut doc: SessionDoc = serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+
+        let mut version = doc
+            .get("schema_version")
+            .and_then(|v| v.as_str())
+            .unwrap_or("1")
+            .to_string();
+
+        for migration in migrations() {
+            if version == migration.version {
+                tracing::info!(from_version = migration.version, path = %path.display(), "applying saved-session migration");
+                (migration.apply)(&mut doc);
+                version = next_version(&version);
+                if let Some(obj) = doc.as_object_mut() {
+                    obj.insert("schema_version".to_string(), serde_json::Value::String(version.clone()));
+                }
+            }
+        }
+
+        serde_json::from_value(doc).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,188 @@
+//! Counter/trigger evaluation engine
+//!
+//! `CounterConditionEditor` lets the player author guards like "counter X >=
+//! N" against named in-fight counters (stack counts, boss casts, adds
+//! spawned), but authoring one is only half the feature: something has to
+//! keep the counters current and re-check the guards as the fight
+//! progresses. [`TriggerEngine`] is that something - a tick-driven
+//! evaluator, not an event-driven one, so a burst of counter updates
+//! between ticks collapses into a single re-check instead of one
+//! evaluation per update.
+//!
+//! Evaluation is edge-triggered: a [`TriggerDefinition`] fires once when its
+//! condition goes from unsatisfied to satisfied, then stays quiet (even if
+//! re-checked every tick) until the condition goes false and becomes true
+//! again. This is what keeps a long-held "stacks >= 3" guard from spamming
+//! a new callout every tick for as long as the stacks are up.
+
+use std::collections::{HashMap, HashSet};
+
+/// Comparison used by a [`CounterCondition`] guard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOp {
+    Eq,
+    Lt,
+    Gt,
+    Lte,
+    Gte,
+    Ne,
+}
+
+impl ComparisonOp {
+    fn evaluate(&self, lhs: i64, rhs: i64) -> bool {
+        match self {
+            ComparisonOp::Eq => lhs == rhs,
+            ComparisonOp::Lt => lhs < rhs,
+            ComparisonOp::Gt => lhs > rhs,
+            ComparisonOp::Lte => lhs <= rhs,
+            ComparisonOp::Gte => lhs >= rhs,
+            ComparisonOp::Ne => lhs != rhs,
+        }
+    }
+}
+
+/// A guard over one named counter, e.g. "`stacks` >= 3".
+///
+/// A counter that hasn't been seen yet (no boss cast, no stack gained)
+/// evaluates as `0` rather than an error, so e.g. `stacks >= 3` stays
+/// unsatisfied until the counter appears, while `stacks < 3` is satisfied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterCondition {
+    pub counter_id: String,
+    pub operator: ComparisonOp,
+    pub value: i64,
+}
+
+impl CounterCondition {
+    fn is_satisfied(&self, counters: &CounterRegistry) -> bool {
+        self.operator.evaluate(counters.get(&self.counter_id), self.value)
+    }
+}
+
+/// Live map of named counters, updated as combat events stream in.
+///
+/// Counter IDs are whatever the parsing session assigns them (an ability
+/// name, a boss-phase label, a synthetic "adds_alive" tally, ...); this
+/// registry doesn't care what they mean, only that they're named and
+/// numeric. `CombatService` exposes the live ID set to
+/// `CounterConditionEditor` so players only ever pick from counters that
+/// actually exist in the current fight.
+#[derive(Debug, Clone, Default)]
+pub struct CounterRegistry {
+    counters: HashMap<String, i64>,
+}
+
+impl CounterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, counter_id: &str) -> i64 {
+        self.counters.get(counter_id).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, counter_id: impl Into<String>, value: i64) {
+        self.counters.insert(counter_id.into(), value);
+    }
+
+    /// Add `delta` to a counter, creating it at `delta` if it didn't exist.
+    pub fn increment(&mut self, counter_id: &str, delta: i64) {
+        *self.counters.entry(counter_id.to_string()).or_insert(0) += delta;
+    }
+
+    /// Counter IDs currently tracked, for populating `CounterConditionEditor`.
+    pub fn ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.counters.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Reset every counter, e.g. when a new pull starts.
+    pub fn clear(&mut self) {
+        self.counters.clear();
+    }
+}
+
+/// One armed phase/timer trigger: a guard plus what to announce when it
+/// first fires.
+#[derive(Debug, Clone)]
+pub struct TriggerDefinition {
+    pub id: String,
+    pub condition: CounterCondition,
+    pub message: String,
+    /// For timed phases: how long the announced countdown should run, in
+    /// seconds. `None` for a plain one-shot callout.
+    pub countdown_secs: Option<f32>,
+}
+
+/// Emitted the instant a [`TriggerDefinition`]'s condition transitions from
+/// unsatisfied to satisfied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerFired {
+    pub id: String,
+    pub message: String,
+    pub countdown_secs: Option<f32>,
+}
+
+/// Owns the counter registry and the armed trigger set, and re-evaluates
+/// them together on every [`TriggerEngine::tick`].
+#[derive(Debug, Default)]
+pub struct TriggerEngine {
+    counters: CounterRegistry,
+    definitions: Vec<TriggerDefinition>,
+    /// IDs whose condition was satisfied as of the last tick, so a
+    /// still-true condition doesn't refire every tick.
+    armed: HashSet<String>,
+}
+
+impl TriggerEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn counters(&self) -> &CounterRegistry {
+        &self.counters
+    }
+
+    pub fn counters_mut(&mut self) -> &mut CounterRegistry {
+        &mut self.counters
+    }
+
+    /// Replace the armed trigger set, e.g. when the player saves changes in
+    /// the encounter editor. Clears edge-trigger state so every definition
+    /// gets a fresh unsatisfied->satisfied check.
+    pub fn set_definitions(&mut self, definitions: Vec<TriggerDefinition>) {
+        self.definitions = definitions;
+        self.armed.clear();
+    }
+
+    /// Re-check every armed definition against the current counters,
+    /// returning the ones that just transitioned to satisfied.
+    pub fn tick(&mut self) -> Vec<TriggerFired> {
+        let mut fired = Vec::new();
+
+        for definition in &self.definitions {
+            let satisfied = definition.condition.is_satisfied(&self.counters);
+
+            if satisfied {
+                if self.armed.insert(definition.id.clone()) {
+                    fired.push(TriggerFired {
+                        id: definition.id.clone(),
+                        message: definition.message.clone(),
+                        countdown_secs: definition.countdown_secs,
+                    });
+                }
+            } else {
+                self.armed.remove(&definition.id);
+            }
+        }
+
+        fired
+    }
+
+    /// Reset counters and edge-trigger state for a new pull.
+    pub fn reset(&mut self) {
+        self.counters.clear();
+        self.armed.clear();
+    }
+}
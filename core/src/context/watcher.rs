@@ -3,6 +3,7 @@ use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watche
 use std::path::{Path, PathBuf};
 use tokio::sync::mpsc::{self, Receiver};
 
+#[derive(Debug)]
 pub enum DirectoryEvent {
     NewFile(PathBuf),
     /// File was modified (grew in size) - useful for re-checking character on empty files
@@ -67,6 +68,16 @@ impl DirectoryWatcher {
     /// This method is intentionally non-blocking - it immediately returns without
     /// waiting for file content or any other condition.
     fn process_event(&self, event: Event) -> Option<DirectoryEvent> {
+        let watcher_event = self.classify_event(event);
+        if crate::context::print_events_enabled() {
+            if let Some(event) = &watcher_event {
+                tracing::trace!(timestamp = ?std::time::SystemTime::now(), event = ?event, "DirectoryEvent");
+            }
+        }
+        watcher_event
+    }
+
+    fn classify_event(&self, event: Event) -> Option<DirectoryEvent> {
         match event.kind {
             EventKind::Create(_) => {
                 for path in event.paths {
@@ -0,0 +1,58 @@
+//! Live name filter for `MeterOverlay`
+//!
+//! A small case-insensitive subsequence fuzzy matcher: every character of
+//! the query must appear in the candidate name, in order, but not
+//! necessarily contiguously. Matches score higher the more contiguous (and
+//! boundary-aligned) they are, so `"jk"` ranks `"Jedi Knight"` above
+//! `"Jakku"`.
+
+/// Score awarded per matched character.
+const BASE_SCORE: i64 = 1;
+/// Extra score when this match immediately follows the previous one.
+const CONSECUTIVE_BONUS: i64 = 5;
+/// Extra score when the match is the first character, follows a separator
+/// (space/underscore/hyphen/apostrophe), or follows a lowercase-to-uppercase
+/// (camelCase) transition.
+const BOUNDARY_BONUS: i64 = 10;
+
+/// Case-insensitively test whether `query` is a subsequence of `name`,
+/// returning a score if so (higher is a better match) or `None` if `query`
+/// isn't fully matched. An empty `query` matches everything with score `0`.
+pub(crate) fn fuzzy_match(query: &str, name: &str) -> Option<i64> {
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let name_chars: Vec<char> = name.chars().collect();
+    let mut score = 0i64;
+    let mut query_index = 0;
+    let mut prev_matched = false;
+
+    for (i, &ch) in name_chars.iter().enumerate() {
+        if query_index >= query.len() {
+            break;
+        }
+        if ch.to_ascii_lowercase() != query[query_index].to_ascii_lowercase() {
+            prev_matched = false;
+            continue;
+        }
+
+        score += BASE_SCORE;
+        if prev_matched {
+            score += CONSECUTIVE_BONUS;
+        }
+
+        let at_boundary = i == 0
+            || matches!(name_chars[i - 1], ' ' | '_' | '-' | '\'')
+            || (name_chars[i - 1].is_lowercase() && ch.is_uppercase());
+        if at_boundary {
+            score += BOUNDARY_BONUS;
+        }
+
+        prev_matched = true;
+        query_index += 1;
+    }
+
+    (query_index == query.len()).then_some(score)
+}
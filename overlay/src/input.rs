@@ -0,0 +1,193 @@
+//! Keybinding layer: parsed key chords, platform-agnostic input events, and
+//! the actions `MeterOverlay` dispatches them to.
+//!
+//! Modeled on yazi's `config/keymap` (`key.rs`/`exec.rs`): a [`Key`] parses
+//! from strings like `"ctrl+r"`, and a [`Keymap`] maps parsed keys to named
+//! [`Action`]s. Platform backends surface raw presses through
+//! [`crate::platform::OverlayPlatform::poll_events`] as [`InputEvent`]s;
+//! `MeterOverlay` resolves them against the active theme's keymap and
+//! dispatches the matching action.
+
+use std::collections::HashMap;
+
+/// A parsed key chord, e.g. `ctrl+r`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Key {
+    /// Lowercase name of the non-modifier key, e.g. `"r"`, `"1"`, `"escape"`.
+    pub code: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl Key {
+    /// Parse a chord like `"ctrl+shift+r"`. At most one non-modifier token is
+    /// allowed; an empty token or a second non-modifier token fails the parse.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut ctrl = false;
+        let mut alt = false;
+        let mut shift = false;
+        let mut code = None;
+
+        for part in s.split('+') {
+            let part = part.trim();
+            if part.is_empty() {
+                return None;
+            }
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ctrl = true,
+                "alt" => alt = true,
+                "shift" => shift = true,
+                other => {
+                    if code.is_some() {
+                        return None;
+                    }
+                    code = Some(other.to_string());
+                }
+            }
+        }
+
+        Some(Self { code: code?, ctrl, alt, shift })
+    }
+}
+
+/// A platform event surfaced through `poll_events`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputEvent {
+    /// A key chord was pressed.
+    KeyPress(Key),
+    /// The window should close.
+    CloseRequested,
+}
+
+/// Built-in actions a keymap entry can dispatch to, handled by
+/// [`crate::manager::MeterOverlay::poll_events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Toggle whether pointer/keyboard input passes through the overlay.
+    ToggleClickThrough,
+    /// Clear the currently displayed entries. Resetting the upstream
+    /// encounter aggregates themselves is the host application's
+    /// responsibility (`MeterOverlay` only holds the latest snapshot handed
+    /// to it via `set_entries`).
+    ResetEncounter,
+    /// Cycle the displayed metric through `dps` -> `hps` -> `threat`.
+    CycleMetric,
+    /// Toggle move-mode (drag-to-reposition). Actually hiding the overlay
+    /// window is the host application's responsibility (e.g. the `app`
+    /// crate's `hide_overlay` command), out of this crate's scope.
+    ToggleMoveMode,
+}
+
+impl Action {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "toggle_click_through" => Some(Self::ToggleClickThrough),
+            "reset_encounter" => Some(Self::ResetEncounter),
+            "cycle_metric" => Some(Self::CycleMetric),
+            "toggle_move_mode" => Some(Self::ToggleMoveMode),
+            _ => None,
+        }
+    }
+}
+
+/// Maps parsed [`Key`] chords to [`Action`]s.
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    bindings: HashMap<Key, Action>,
+}
+
+impl Keymap {
+    /// The built-in bindings every overlay starts with.
+    pub fn defaults() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(Key { code: "t".to_string(), ctrl: true, alt: false, shift: false }, Action::ToggleClickThrough);
+        bindings.insert(Key { code: "r".to_string(), ctrl: true, alt: false, shift: false }, Action::ResetEncounter);
+        bindings.insert(Key { code: "m".to_string(), ctrl: true, alt: false, shift: false }, Action::CycleMetric);
+        bindings.insert(Key { code: "g".to_string(), ctrl: true, alt: false, shift: false }, Action::ToggleMoveMode);
+        Self { bindings }
+    }
+
+    /// Start from [`Keymap::defaults`] and apply `overrides` (chord string ->
+    /// action name, as loaded from a theme TOML's `[keybindings]` table).
+    /// Entries that fail to parse are skipped rather than rejecting the
+    /// whole file, unlike a malformed color in [`crate::theme::Theme`].
+    pub fn with_overrides(overrides: &HashMap<String, String>) -> Self {
+        let mut keymap = Self::defaults();
+        for (key_str, action_str) in overrides {
+            let Some(key) = Key::parse(key_str) else { continue };
+            let Some(action) = Action::parse(action_str) else { continue };
+            keymap.bindings.insert(key, action);
+        }
+        keymap
+    }
+
+    pub fn action_for(&self, key: &Key) -> Option<Action> {
+        self.bindings.get(key).copied()
+    }
+}
+
+/// Classification of a raw Linux evdev keycode (`linux/input-event-codes.h`),
+/// used by the Wayland and X11 backends to build a [`Key`] without pulling in
+/// a full XKB keysym table.
+pub(crate) enum EvdevKey {
+    Ctrl,
+    Alt,
+    Shift,
+    Named(&'static str),
+    Unknown,
+}
+
+/// Classify an evdev keycode. Covers the modifier keys plus digits, letters,
+/// and a handful of named keys - enough for the default keymap and any
+/// reasonable user override. An unrecognized keycode (function keys,
+/// navigation keys, etc.) is reported `Unknown` rather than guessed at.
+pub(crate) fn classify_evdev_key(code: u32) -> EvdevKey {
+    match code {
+        29 | 97 => EvdevKey::Ctrl,
+        56 | 100 => EvdevKey::Alt,
+        42 | 54 => EvdevKey::Shift,
+        1 => EvdevKey::Named("escape"),
+        14 => EvdevKey::Named("backspace"),
+        15 => EvdevKey::Named("tab"),
+        28 => EvdevKey::Named("enter"),
+        57 => EvdevKey::Named("space"),
+        2 => EvdevKey::Named("1"),
+        3 => EvdevKey::Named("2"),
+        4 => EvdevKey::Named("3"),
+        5 => EvdevKey::Named("4"),
+        6 => EvdevKey::Named("5"),
+        7 => EvdevKey::Named("6"),
+        8 => EvdevKey::Named("7"),
+        9 => EvdevKey::Named("8"),
+        10 => EvdevKey::Named("9"),
+        11 => EvdevKey::Named("0"),
+        16 => EvdevKey::Named("q"),
+        17 => EvdevKey::Named("w"),
+        18 => EvdevKey::Named("e"),
+        19 => EvdevKey::Named("r"),
+        20 => EvdevKey::Named("t"),
+        21 => EvdevKey::Named("y"),
+        22 => EvdevKey::Named("u"),
+        23 => EvdevKey::Named("i"),
+        24 => EvdevKey::Named("o"),
+        25 => EvdevKey::Named("p"),
+        30 => EvdevKey::Named("a"),
+        31 => EvdevKey::Named("s"),
+        32 => EvdevKey::Named("d"),
+        33 => EvdevKey::Named("f"),
+        34 => EvdevKey::Named("g"),
+        35 => EvdevKey::Named("h"),
+        36 => EvdevKey::Named("j"),
+        37 => EvdevKey::Named("k"),
+        38 => EvdevKey::Named("l"),
+        44 => EvdevKey::Named("z"),
+        45 => EvdevKey::Named("x"),
+        46 => EvdevKey::Named("c"),
+        47 => EvdevKey::Named("v"),
+        48 => EvdevKey::Named("b"),
+        49 => EvdevKey::Named("n"),
+        50 => EvdevKey::Named("m"),
+        _ => EvdevKey::Unknown,
+    }
+}
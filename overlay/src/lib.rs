@@ -4,11 +4,19 @@
 //! Provides platform abstraction for Wayland, X11, Windows, and macOS,
 //! with software rendering using tiny-skia and cosmic-text.
 
+mod filter;
+pub mod icon;
+pub mod input;
 pub mod manager;
+pub mod overlays;
 pub mod platform;
 pub mod renderer;
+pub mod theme;
 
 // Re-export commonly used types
-pub use manager::{MeterEntry, MeterOverlay, OverlayWindow};
+pub use icon::{DecodedImage, IconCache, IconHandle};
+pub use input::{Action, InputEvent, Key, Keymap};
+pub use manager::{MeterEntry, MeterOverlay, OverlayWindow, SortMode};
 pub use platform::{NativeOverlay, OverlayConfig, OverlayPlatform, PlatformError};
 pub use renderer::{Renderer, colors};
+pub use theme::{Theme, ThemeEvent, ThemeWatcher};
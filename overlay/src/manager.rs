@@ -3,8 +3,13 @@
 //! This module provides a high-level interface for creating and managing
 //! multiple overlay windows, each with its own content.
 
+use crate::filter;
+use crate::icon::IconHandle;
+use crate::input::{Action, InputEvent, Key};
 use crate::platform::{NativeOverlay, OverlayConfig, OverlayPlatform, PlatformError};
 use crate::renderer::{Renderer, colors};
+use crate::theme::{Theme, ThemeEvent, ThemeWatcher};
+use std::path::Path;
 use tiny_skia::Color;
 
 /// A managed overlay window with its own renderer
@@ -47,6 +52,12 @@ impl OverlayWindow {
         self.platform.set_click_through(enabled);
     }
 
+    /// Toggle move-mode: while enabled, a pointer drag on the overlay
+    /// repositions it instead of passing through.
+    pub fn set_move_mode(&mut self, enabled: bool) {
+        self.platform.set_move_mode(enabled);
+    }
+
     /// Clear the overlay with a color
     pub fn clear(&mut self, color: Color) {
         let width = self.platform.width();
@@ -126,6 +137,21 @@ impl OverlayWindow {
         }
     }
 
+    /// Decode (or reuse a cached decode of) the PNG at `path`, keyed by `key`.
+    pub fn load_icon(&mut self, key: &str, path: &Path) -> Option<IconHandle> {
+        self.renderer.load_icon(key, path)
+    }
+
+    /// Draw `icon` at `(x, y)`, downscaled to `target_w x target_h`
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_image(&mut self, icon: &IconHandle, x: f32, y: f32, target_w: u32, target_h: u32) {
+        let width = self.platform.width();
+        let height = self.platform.height();
+        if let Some(buffer) = self.platform.pixel_buffer() {
+            self.renderer.draw_image(buffer, width, height, icon, x, y, target_w, target_h);
+        }
+    }
+
     /// Draw text at the specified position
     pub fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color) {
         let width = self.platform.width();
@@ -146,9 +172,15 @@ impl OverlayWindow {
         self.platform.commit();
     }
 
-    /// Poll for events (non-blocking)
-    /// Returns false if the window should close
-    pub fn poll_events(&mut self) -> bool {
+    /// Mark the window dirty so the next paint-cycle opportunity actually
+    /// redraws it, per [`OverlayPlatform::request_redraw`].
+    pub fn request_redraw(&mut self) {
+        self.platform.request_redraw();
+    }
+
+    /// Poll for events (non-blocking), returning any key presses/close
+    /// requests that arrived since the last call.
+    pub fn poll_events(&mut self) -> Vec<InputEvent> {
         self.platform.poll_events()
     }
 
@@ -173,12 +205,45 @@ impl OverlayWindow {
         F: FnMut(&mut Self),
     {
         // We need to implement our own loop since we can't pass self through the platform
-        while self.poll_events() {
+        loop {
+            let events = self.poll_events();
+            if events.iter().any(|e| matches!(e, InputEvent::CloseRequested)) {
+                break;
+            }
             render_callback(self);
         }
     }
 }
 
+/// How `MeterOverlay::render` orders entries before drawing, mirroring
+/// yazi's `config/manager/sorting.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    #[default]
+    ValueDesc,
+    ValueAsc,
+    NameAsc,
+    NameDesc,
+    None,
+}
+
+impl SortMode {
+    /// Stable sort `entries` in place per this mode.
+    fn apply(self, entries: &mut [MeterEntry]) {
+        match self {
+            SortMode::ValueDesc => {
+                entries.sort_by(|a, b| b.value.partial_cmp(&a.value).unwrap_or(std::cmp::Ordering::Equal))
+            }
+            SortMode::ValueAsc => {
+                entries.sort_by(|a, b| a.value.partial_cmp(&b.value).unwrap_or(std::cmp::Ordering::Equal))
+            }
+            SortMode::NameAsc => entries.sort_by(|a, b| a.name.cmp(&b.name)),
+            SortMode::NameDesc => entries.sort_by(|a, b| b.name.cmp(&a.name)),
+            SortMode::None => {}
+        }
+    }
+}
+
 /// Entry in a DPS/HPS meter
 #[derive(Debug, Clone)]
 pub struct MeterEntry {
@@ -186,6 +251,11 @@ pub struct MeterEntry {
     pub value: f64,
     pub max_value: f64,
     pub color: Color,
+    /// `Entity.class_id` this entry belongs to, if known, so the active
+    /// `Theme` can substitute a per-class bar color.
+    pub class_id: Option<i64>,
+    /// Class/ability icon drawn at the left edge of the bar, if any.
+    pub icon: Option<IconHandle>,
 }
 
 /// A specialized DPS/HPS meter overlay
@@ -197,11 +267,38 @@ pub struct MeterOverlay {
     bar_spacing: f32,
     padding: f32,
     font_size: f32,
+    /// Side length icons are drawn at, reserving that much horizontal space
+    /// (plus a small gap) at the left edge of each bar.
+    icon_size: f32,
+    sort_mode: SortMode,
+    /// Entries beyond this are folded into a single synthetic "Others" row,
+    /// so the overlay's height stays bounded regardless of raid size.
+    max_rows: usize,
+    theme: Theme,
+    /// Metric this overlay displays (`"dps"`, `"hps"`, ...), used to look up
+    /// the theme's per-metric color override. `None` skips that lookup.
+    metric: Option<String>,
+    theme_watcher: Option<ThemeWatcher>,
+    /// Tracked locally so `Action::ToggleClickThrough` has something to
+    /// flip; the host application may also change this directly via
+    /// `window_mut().set_click_through`, in which case this falls out of
+    /// sync until the next toggle (same caveat `OverlayLayout` in the `app`
+    /// crate already has to live with).
+    click_through: bool,
+    move_mode: bool,
+    /// Live name filter typed via keyboard input. Empty shows every entry in
+    /// `sort_mode` order; non-empty filters to subsequence-fuzzy matches of
+    /// this query, sorted by match score instead.
+    query: String,
 }
 
+/// Metrics `Action::CycleMetric` cycles through, in order.
+const CYCLE_METRICS: [&str; 3] = ["dps", "hps", "threat"];
+
 impl MeterOverlay {
     /// Create a new meter overlay
     pub fn new(config: OverlayConfig, title: &str) -> Result<Self, PlatformError> {
+        let click_through = config.click_through;
         let window = OverlayWindow::new(config)?;
 
         Ok(Self {
@@ -212,6 +309,15 @@ impl MeterOverlay {
             bar_spacing: 4.0,
             padding: 8.0,
             font_size: 14.0,
+            icon_size: 16.0,
+            sort_mode: SortMode::default(),
+            max_rows: 8,
+            theme: Theme::defaults(),
+            metric: None,
+            theme_watcher: None,
+            click_through,
+            move_mode: false,
+            query: String::new(),
         })
     }
 
@@ -220,13 +326,55 @@ impl MeterOverlay {
         self.entries = entries;
     }
 
+    /// Set how entries are ordered before rendering.
+    pub fn set_sort_mode(&mut self, sort_mode: SortMode) {
+        self.sort_mode = sort_mode;
+    }
+
+    /// Set how many rows render before the remainder is folded into a
+    /// single "Others" row.
+    pub fn set_max_rows(&mut self, max_rows: usize) {
+        self.max_rows = max_rows.max(1);
+    }
+
     /// Set the title
     pub fn set_title(&mut self, title: &str) {
         self.title = title.to_string();
     }
 
+    /// Replace the active color theme.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Set the metric name (`"dps"`, `"hps"`, ...) used to look up the
+    /// theme's per-metric color override.
+    pub fn set_metric(&mut self, metric: impl Into<String>) {
+        self.metric = Some(metric.into());
+    }
+
+    /// Load the theme from `path` and start watching it for changes, so
+    /// edits to the file hot-reload the overlay without a restart.
+    pub fn watch_theme_file(&mut self, path: &Path) -> notify::Result<()> {
+        self.theme = Theme::load_from(path);
+        self.theme_watcher = Some(ThemeWatcher::new(path)?);
+        Ok(())
+    }
+
+    /// Decode (or reuse a cached decode of) the PNG at `path`, keyed by
+    /// `key`, for use as a `MeterEntry`'s icon.
+    pub fn load_icon(&mut self, key: &str, path: &Path) -> Option<IconHandle> {
+        self.window.load_icon(key, path)
+    }
+
     /// Render the meter
     pub fn render(&mut self) {
+        if let Some(watcher) = &mut self.theme_watcher {
+            if let Some(ThemeEvent::Reloaded(theme)) = watcher.poll() {
+                self.theme = theme;
+            }
+        }
+
         let width = self.window.width() as f32;
         let height = self.window.height() as f32;
 
@@ -237,14 +385,20 @@ impl MeterOverlay {
         self.window
             .fill_rounded_rect(0.0, 0.0, width, height, 8.0, colors::overlay_bg());
 
-        // Draw title
+        // Draw title, appending the active filter query (if any) so the
+        // user can see what's applied.
         let title_y = self.padding + self.font_size;
+        let title_text = if self.query.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{} — {}", self.title, self.query)
+        };
         self.window.draw_text(
-            &self.title,
+            &title_text,
             self.padding,
             title_y,
             self.font_size,
-            colors::white(),
+            self.theme.title_text(),
         );
 
         // Draw separator line
@@ -254,19 +408,70 @@ impl MeterOverlay {
             sep_y,
             width - self.padding * 2.0,
             1.0,
-            colors::white(),
+            self.theme.separator(),
         );
 
+        // With an active filter query, keep only subsequence-fuzzy matches
+        // of `self.entries`' names and order by match score instead of
+        // `sort_mode`; an empty query keeps the existing sort-then-fold
+        // behavior unchanged.
+        let mut display_entries = if self.query.is_empty() {
+            let mut entries = self.entries.clone();
+            self.sort_mode.apply(&mut entries);
+            entries
+        } else {
+            let mut scored: Vec<(i64, MeterEntry)> = self
+                .entries
+                .iter()
+                .filter_map(|entry| filter::fuzzy_match(&self.query, &entry.name).map(|score| (score, entry.clone())))
+                .collect();
+            scored.sort_by(|a, b| b.0.cmp(&a.0));
+            scored.into_iter().map(|(_, entry)| entry).collect()
+        };
+
+        // Fold anything past `max_rows` into one "Others" row, so the
+        // overlay's height stays bounded regardless of raid size. Skipped
+        // while filtering, since a filtered list is already small and
+        // folding its tail into "Others" would just hide matches.
+        let has_others = self.query.is_empty() && display_entries.len() > self.max_rows;
+        if has_others {
+            let overflow = display_entries.split_off(self.max_rows);
+            let value: f64 = overflow.iter().map(|e| e.value).sum();
+            let max_value: f64 = overflow.iter().map(|e| e.max_value).sum();
+            display_entries.push(MeterEntry {
+                name: format!("Others ({})", overflow.len()),
+                value,
+                max_value: max_value.max(value),
+                color: self.theme.others_bar_fill(),
+                class_id: None,
+                icon: None,
+            });
+        }
+
         // Find max value for scaling
-        let max_val = self.entries.iter().map(|e| e.max_value).fold(1.0, f64::max);
+        let max_val = display_entries.iter().map(|e| e.max_value).fold(1.0, f64::max);
 
         // Draw entries
         let bar_width = width - self.padding * 2.0;
         let mut y = sep_y + self.bar_spacing + 4.0;
+        let last_index = display_entries.len().saturating_sub(1);
 
-        for entry in &self.entries {
+        for (index, entry) in display_entries.iter().enumerate() {
             let progress = (entry.value / max_val) as f32;
 
+            // The synthetic "Others" row always uses the theme's muted
+            // color; real entries resolve per-class, then per-metric, then
+            // their own computed color.
+            let fill_color = if has_others && index == last_index {
+                entry.color
+            } else {
+                entry
+                    .class_id
+                    .and_then(|class_id| self.theme.class_color(class_id))
+                    .or_else(|| self.metric.as_deref().and_then(|metric| self.theme.metric_color(metric)))
+                    .unwrap_or(entry.color)
+            };
+
             // Draw bar
             self.window.draw_progress_bar(
                 self.padding,
@@ -274,16 +479,30 @@ impl MeterOverlay {
                 bar_width,
                 self.bar_height,
                 progress,
-                colors::dps_bar_bg(),
-                entry.color,
+                self.theme.bar_bg(),
+                fill_color,
                 4.0,
             );
 
+            // Draw the icon (if any) at the left edge of the bar, reserving
+            // its width plus a small gap for the name text that follows.
+            let icon_gap = if entry.icon.is_some() { self.icon_size + 4.0 } else { 0.0 };
+            if let Some(icon) = &entry.icon {
+                let icon_y = y + (self.bar_height - self.icon_size) / 2.0;
+                self.window.draw_image(
+                    icon,
+                    self.padding + 4.0,
+                    icon_y,
+                    self.icon_size as u32,
+                    self.icon_size as u32,
+                );
+            }
+
             // Draw name on the left
             let text_y = y + self.bar_height / 2.0 + self.font_size / 3.0;
             self.window.draw_text(
                 &entry.name,
-                self.padding + 4.0,
+                self.padding + 4.0 + icon_gap,
                 text_y,
                 self.font_size - 2.0,
                 colors::white(),
@@ -310,10 +529,12 @@ impl MeterOverlay {
             let corner_y = height - indicator_size - 4.0;
 
             // Draw a small triangle/grip indicator
+            let resize_grip = self.theme.resize_grip();
             let highlight = if self.window.is_resizing() {
-                colors::white()
+                resize_grip
             } else {
-                Color::from_rgba8(255, 255, 255, 180)
+                let rgb = resize_grip.to_color_u8();
+                Color::from_rgba8(rgb.red(), rgb.green(), rgb.blue(), 180)
             };
 
             // Draw diagonal lines as resize grip
@@ -339,9 +560,72 @@ impl MeterOverlay {
         self.window.commit();
     }
 
-    /// Poll for events
+    /// Poll for events, dispatching any key presses against the active
+    /// theme's keymap. Returns `false` if the window should close.
     pub fn poll_events(&mut self) -> bool {
-        self.window.poll_events()
+        let events = self.window.poll_events();
+        let keymap = self.theme.keymap();
+        let mut still_open = true;
+
+        for event in events {
+            match event {
+                InputEvent::CloseRequested => still_open = false,
+                InputEvent::KeyPress(key) => {
+                    if let Some(action) = keymap.action_for(&key) {
+                        self.dispatch_action(action);
+                    } else {
+                        self.handle_filter_key(&key);
+                    }
+                }
+            }
+        }
+
+        still_open
+    }
+
+    /// Update the live name filter (`self.query`) from a key press that
+    /// isn't bound to a keymap action, so typing filters entries without a
+    /// dedicated text-input widget.
+    fn handle_filter_key(&mut self, key: &Key) {
+        if key.ctrl || key.alt {
+            return;
+        }
+        match key.code.as_str() {
+            "escape" => self.query.clear(),
+            "backspace" => {
+                self.query.pop();
+            }
+            "space" => self.query.push(' '),
+            code if code.chars().count() == 1 => {
+                let ch = code.chars().next().expect("checked len above");
+                self.query.push(if key.shift { ch.to_ascii_uppercase() } else { ch });
+            }
+            _ => {}
+        }
+    }
+
+    /// Run a built-in keybinding action.
+    fn dispatch_action(&mut self, action: Action) {
+        match action {
+            Action::ToggleClickThrough => {
+                self.click_through = !self.click_through;
+                self.window.set_click_through(self.click_through);
+            }
+            Action::ResetEncounter => self.entries.clear(),
+            Action::CycleMetric => {
+                let current = self.metric.as_deref().unwrap_or(CYCLE_METRICS[0]);
+                let next_index = CYCLE_METRICS
+                    .iter()
+                    .position(|m| *m == current)
+                    .map(|i| (i + 1) % CYCLE_METRICS.len())
+                    .unwrap_or(0);
+                self.metric = Some(CYCLE_METRICS[next_index].to_string());
+            }
+            Action::ToggleMoveMode => {
+                self.move_mode = !self.move_mode;
+                self.window.set_move_mode(self.move_mode);
+            }
+        }
     }
 
     /// Get mutable access to the underlying window
@@ -1,9 +1,9 @@
 //! Complete overlay implementations
 //!
 //! Each overlay type is a self-contained window that displays specific
-//! combat information. Overlays use widgets for rendering and the platform
-//! layer for window management.
+//! combat information, rendering directly onto an [`crate::manager::OverlayWindow`]
+//! the same way [`crate::manager::MeterOverlay`] does.
 
-mod metric;
+mod session_info;
 
-pub use metric::{MeterEntry, MetricOverlay};
+pub use session_info::{SessionInfoData, SessionInfoOverlay};
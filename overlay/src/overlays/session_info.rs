@@ -0,0 +1,92 @@
+//! Session info overlay
+//!
+//! A small always-on status footer showing which combat log is being
+//! tailed, the detected character, the current encounter, and fight
+//! duration/encounter count for the session - so it's obvious at a glance
+//! that baras is reading the right file and isn't silently stalled.
+
+use crate::manager::OverlayWindow;
+use crate::renderer::colors;
+
+/// Data displayed by [`SessionInfoOverlay`], sourced from the host
+/// application's view of the tailed session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionInfoData {
+    pub file_name: String,
+    pub character_name: Option<String>,
+    pub discipline: Option<String>,
+    pub encounter_name: Option<String>,
+    pub encounter_duration_secs: f64,
+    pub encounter_count: u32,
+    pub total_events: u64,
+}
+
+/// Renders [`SessionInfoData`] directly onto an [`OverlayWindow`], the same
+/// way [`crate::manager::MeterOverlay`] draws its own rows - a label/value
+/// per row plus a header and footer line, no intermediate widget layer.
+#[derive(Debug, Clone, Default)]
+pub struct SessionInfoOverlay {
+    pub data: SessionInfoData,
+}
+
+impl SessionInfoOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_data(&mut self, data: SessionInfoData) {
+        self.data = data;
+    }
+
+    fn character_label(&self) -> String {
+        match (&self.data.character_name, &self.data.discipline) {
+            (Some(name), Some(disc)) => format!("{name} ({disc})"),
+            (Some(name), None) => name.clone(),
+            (None, _) => "Unknown".to_string(),
+        }
+    }
+
+    fn duration_label(&self) -> String {
+        let secs = self.data.encounter_duration_secs as i64;
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+
+    /// Render onto `window`'s current frame, starting at `(x, y)`. Returns
+    /// the total height used, for callers stacking this below other content.
+    pub fn render(&self, window: &mut OverlayWindow, x: f32, y: f32, width: f32, font_size: f32) -> f32 {
+        let spacing = font_size * 0.4;
+        let line_height = font_size + spacing;
+        let mut cursor_y = y + font_size;
+
+        let file_name = if self.data.file_name.is_empty() {
+            "No log file".to_string()
+        } else {
+            self.data.file_name.clone()
+        };
+        window.draw_text(&file_name, x, cursor_y, font_size, colors::white());
+        cursor_y += spacing;
+        window.fill_rect(x, cursor_y, width, 1.0, colors::label_dim());
+        cursor_y += spacing + line_height;
+
+        let encounter_name = self.data.encounter_name.as_deref().unwrap_or("No active encounter");
+        let rows = [
+            ("Character", self.character_label()),
+            ("Encounter", encounter_name.to_string()),
+            ("Duration", self.duration_label()),
+            ("Encounters", self.data.encounter_count.to_string()),
+        ];
+
+        for (label, value) in &rows {
+            window.draw_text(label, x, cursor_y, font_size * 0.9, colors::label_dim());
+            let (value_width, _) = window.measure_text(value, font_size * 0.9);
+            window.draw_text(value, x + width - value_width, cursor_y, font_size * 0.9, colors::white());
+            cursor_y += line_height;
+        }
+
+        let footer = format!("{} events", self.data.total_events);
+        window.draw_text(&footer, x, cursor_y, font_size * 0.85, colors::label_dim());
+        cursor_y += line_height;
+
+        cursor_y - y
+    }
+}
@@ -0,0 +1,227 @@
+//! Platform abstraction for overlay windows
+//!
+//! An overlay is a borderless, always-on-top, click-through-capable window
+//! that the renderer draws into via a raw RGBA pixel buffer. Each supported
+//! display server gets its own [`OverlayPlatform`] implementation; `new`
+//! picks the right one at runtime so callers (`OverlayWindow`, `MeterOverlay`)
+//! never branch on session type themselves.
+
+mod wayland;
+mod x11;
+
+use std::fmt;
+
+use crate::input::InputEvent;
+
+/// Platform-agnostic overlay window configuration.
+#[derive(Debug, Clone)]
+pub struct OverlayConfig {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub namespace: String,
+    pub click_through: bool,
+    /// Name of the monitor to pin the overlay to (`wl_output`'s `Name`
+    /// event on Wayland, the RandR output name on X11), if any. `None`
+    /// lets the compositor/window manager choose.
+    pub output: Option<String>,
+}
+
+/// Error constructing or driving an [`OverlayPlatform`].
+#[derive(Debug, Clone)]
+pub enum PlatformError {
+    /// Couldn't connect to (or initialize) the display server.
+    ConnectionFailed(String),
+    /// The display server doesn't support something the overlay needs
+    /// (e.g. a missing Wayland global or X11 extension).
+    UnsupportedFeature(String),
+}
+
+impl fmt::Display for PlatformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlatformError::ConnectionFailed(reason) => write!(f, "failed to connect to display server: {reason}"),
+            PlatformError::UnsupportedFeature(feature) => write!(f, "unsupported feature: {feature}"),
+        }
+    }
+}
+
+impl std::error::Error for PlatformError {}
+
+/// A native overlay window on one particular display server.
+pub trait OverlayPlatform: Sized {
+    fn new(config: OverlayConfig) -> Result<Self, PlatformError>;
+
+    fn width(&self) -> u32;
+    fn height(&self) -> u32;
+
+    fn set_position(&mut self, x: i32, y: i32);
+    fn set_size(&mut self, width: u32, height: u32);
+    fn set_click_through(&mut self, enabled: bool);
+
+    /// Toggle move-mode: while enabled, a pointer drag on the overlay
+    /// repositions it instead of being passed through to whatever's behind it.
+    fn set_move_mode(&mut self, enabled: bool);
+
+    /// Current compositor/monitor scale factor (1.0 if the platform has no
+    /// notion of fractional scaling), so the rendering layer can draw at
+    /// the resolution the pixel buffer actually was allocated at.
+    fn scale_factor(&self) -> f64;
+
+    /// Names of every known output/monitor, for populating a monitor picker.
+    fn available_outputs(&self) -> Vec<String>;
+
+    /// Mark the overlay dirty so the next paint-cycle opportunity actually
+    /// redraws it. The Wayland and X11 backends' `run` otherwise skips
+    /// `render_callback` on wakeups that don't follow a `request_redraw`
+    /// call, so idle overlays (nothing new to show since the last frame)
+    /// burn no CPU repainting an unchanged buffer. [`DynOverlay`]'s `run`
+    /// does not check it.
+    fn request_redraw(&mut self);
+
+    /// Borrow the RGBA pixel buffer the renderer draws into.
+    fn pixel_buffer(&mut self) -> Option<&mut [u8]>;
+    /// Push the pixel buffer to the screen.
+    fn commit(&mut self);
+
+    /// Pump pending display-server events, returning whatever key presses
+    /// (and, via [`InputEvent::CloseRequested`], close requests) arrived
+    /// since the last call.
+    fn poll_events(&mut self) -> Vec<InputEvent>;
+
+    /// Run the event loop, invoking `render_callback` once per configured
+    /// frame until the window closes.
+    fn run<F>(&mut self, render_callback: F)
+    where
+        F: FnMut(&mut Self);
+}
+
+/// The overlay backend for the current session: `wayland` if
+/// `WAYLAND_DISPLAY` is set, `x11` if `DISPLAY` is set instead, matching the
+/// detection order most cross-platform GUI crates (winit, SDL) use so a
+/// Wayland session under XWayland still gets the native Wayland path.
+pub type NativeOverlay = DynOverlay;
+
+/// Dispatches every [`OverlayPlatform`] call to whichever backend
+/// [`DynOverlay::new`] picked at runtime.
+pub enum DynOverlay {
+    Wayland(wayland::WaylandOverlay),
+    X11(x11::X11Overlay),
+}
+
+impl OverlayPlatform for DynOverlay {
+    fn new(config: OverlayConfig) -> Result<Self, PlatformError> {
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            return Ok(Self::Wayland(wayland::WaylandOverlay::new(config)?));
+        }
+        if std::env::var_os("DISPLAY").is_some() {
+            return Ok(Self::X11(x11::X11Overlay::new(config)?));
+        }
+        Err(PlatformError::ConnectionFailed(
+            "neither WAYLAND_DISPLAY nor DISPLAY is set".to_string(),
+        ))
+    }
+
+    fn width(&self) -> u32 {
+        match self {
+            Self::Wayland(o) => o.width(),
+            Self::X11(o) => o.width(),
+        }
+    }
+
+    fn height(&self) -> u32 {
+        match self {
+            Self::Wayland(o) => o.height(),
+            Self::X11(o) => o.height(),
+        }
+    }
+
+    fn set_position(&mut self, x: i32, y: i32) {
+        match self {
+            Self::Wayland(o) => o.set_position(x, y),
+            Self::X11(o) => o.set_position(x, y),
+        }
+    }
+
+    fn set_size(&mut self, width: u32, height: u32) {
+        match self {
+            Self::Wayland(o) => o.set_size(width, height),
+            Self::X11(o) => o.set_size(width, height),
+        }
+    }
+
+    fn set_click_through(&mut self, enabled: bool) {
+        match self {
+            Self::Wayland(o) => o.set_click_through(enabled),
+            Self::X11(o) => o.set_click_through(enabled),
+        }
+    }
+
+    fn set_move_mode(&mut self, enabled: bool) {
+        match self {
+            Self::Wayland(o) => o.set_move_mode(enabled),
+            Self::X11(o) => o.set_move_mode(enabled),
+        }
+    }
+
+    fn scale_factor(&self) -> f64 {
+        match self {
+            Self::Wayland(o) => o.scale_factor(),
+            Self::X11(o) => o.scale_factor(),
+        }
+    }
+
+    fn available_outputs(&self) -> Vec<String> {
+        match self {
+            Self::Wayland(o) => o.available_outputs(),
+            Self::X11(o) => o.available_outputs(),
+        }
+    }
+
+    fn request_redraw(&mut self) {
+        match self {
+            Self::Wayland(o) => o.request_redraw(),
+            Self::X11(o) => o.request_redraw(),
+        }
+    }
+
+    fn pixel_buffer(&mut self) -> Option<&mut [u8]> {
+        match self {
+            Self::Wayland(o) => o.pixel_buffer(),
+            Self::X11(o) => o.pixel_buffer(),
+        }
+    }
+
+    fn commit(&mut self) {
+        match self {
+            Self::Wayland(o) => o.commit(),
+            Self::X11(o) => o.commit(),
+        }
+    }
+
+    fn poll_events(&mut self) -> Vec<InputEvent> {
+        match self {
+            Self::Wayland(o) => o.poll_events(),
+            Self::X11(o) => o.poll_events(),
+        }
+    }
+
+    fn run<F>(&mut self, mut render_callback: F)
+    where
+        F: FnMut(&mut Self),
+    {
+        // Each backend's own `run` expects a callback over *its* concrete
+        // type, not `DynOverlay`, so the loop is reimplemented here in
+        // terms of `poll_events` rather than delegated. It neither blocks
+        // for events nor checks `request_redraw`: `render_callback` runs on
+        // every iteration.
+        loop {
+            let events = self.poll_events();
+            if events.iter().any(|e| matches!(e, InputEvent::CloseRequested)) {
+                break;
+            }
+            render_callback(self);
+        }
+    }
+}
@@ -9,20 +9,41 @@ use rustix::fs::{memfd_create, MemfdFlags};
 use rustix::mm::{mmap, MapFlags, ProtFlags};
 use wayland_client::globals::GlobalListContents;
 use wayland_client::protocol::wl_buffer::WlBuffer;
+use wayland_client::protocol::wl_callback::{self, WlCallback};
 use wayland_client::protocol::wl_compositor::WlCompositor;
+use wayland_client::protocol::wl_keyboard::{self, WlKeyboard};
+use wayland_client::protocol::wl_output::{self, WlOutput};
+use wayland_client::protocol::wl_pointer::{self, WlPointer};
 use wayland_client::protocol::wl_region::WlRegion;
 use wayland_client::protocol::wl_registry;
+use wayland_client::protocol::wl_seat::{self, WlSeat};
 use wayland_client::protocol::wl_shm::{self, Format, WlShm};
 use wayland_client::protocol::wl_shm_pool::WlShmPool;
 use wayland_client::protocol::wl_surface::WlSurface;
-use wayland_client::{Connection, Dispatch, EventQueue, QueueHandle};
+use wayland_client::{Connection, Dispatch, EventQueue, QueueHandle, WEnum};
+use wayland_protocols::wp::fractional_scale::v1::client::{
+    wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1,
+    wp_fractional_scale_v1::{self, WpFractionalScaleV1},
+};
+use wayland_protocols::wp::viewporter::client::{wp_viewport::WpViewport, wp_viewporter::WpViewporter};
 use wayland_protocols_wlr::layer_shell::v1::client::{
     zwlr_layer_shell_v1::ZwlrLayerShellV1,
     zwlr_layer_surface_v1::{self, Anchor, KeyboardInteractivity, ZwlrLayerSurfaceV1},
 };
 
+use crate::input::{self, EvdevKey, InputEvent, Key};
+
 use super::{OverlayConfig, OverlayPlatform, PlatformError};
 
+/// Linux input-event-codes.h `BTN_LEFT`, the button that drives move-mode
+/// dragging.
+const BTN_LEFT: u32 = 0x110;
+
+/// `wp_fractional_scale_v1::Event::PreferredScale` reports scale as a 120ths
+/// integer (e.g. `180` means 1.5x) rather than a float, so the compositor
+/// never has to send a value that can't be represented exactly.
+const SCALE_120_DENOMINATOR: u32 = 120;
+
 /// Wayland overlay implementation
 pub struct WaylandOverlay {
     config: OverlayConfig,
@@ -39,16 +60,84 @@ struct WaylandState {
     width: u32,
     height: u32,
 
+    // Current layer-surface margin, i.e. window position. Mirrored here
+    // (rather than only living on `OverlayConfig`) since the drag handling
+    // below only has access to `WaylandState`, not the owning `WaylandOverlay`.
+    x: i32,
+    y: i32,
+
     // Wayland objects
     compositor: Option<WlCompositor>,
+    layer_shell: Option<ZwlrLayerShellV1>,
     surface: Option<WlSurface>,
     layer_surface: Option<ZwlrLayerSurfaceV1>,
     shm: Option<WlShm>,
-    buffer: Option<WlBuffer>,
+    seat: Option<WlSeat>,
+    pointer: Option<WlPointer>,
+    keyboard: Option<WlKeyboard>,
+
+    // Keyboard state: `wl_keyboard::Event::Key` only gives a raw evdev
+    // keycode, not a symbolic name or modifier mask (that requires parsing
+    // the XKB keymap blob sent separately), so modifier chords are tracked
+    // here by watching the modifier keys' own press/release events, and
+    // `pending_events` accumulates presses for the next `poll_events` call.
+    ctrl_held: bool,
+    alt_held: bool,
+    shift_held: bool,
+    pending_events: Vec<InputEvent>,
+
+    // Output selection: `namespace`/`click_through` are mirrored from
+    // `OverlayConfig` (rather than read back through `WaylandOverlay`) so
+    // `recreate_surface_for_output` can rebuild the surface from `WaylandState`
+    // alone. `target_output` is the configured output name to pin to, if
+    // any; `bound_output` is whichever `WlOutput` the current surface was
+    // actually created against; `outputs` accumulates what's known about
+    // every bound `wl_output` so a late-appearing match can still re-anchor.
+    namespace: String,
+    click_through: bool,
+    target_output: Option<String>,
+    bound_output: Option<WlOutput>,
+    outputs: Vec<(WlOutput, OutputInfo)>,
+
+    // wp_viewporter / wp_fractional_scale_v1: `viewport` maps the
+    // physical-pixel `pixel_data`/shm buffer down to the logical
+    // `width`x`height` rectangle, and `scale_120` (updated from
+    // `PreferredScale`) is what physical size is computed from. Both are
+    // `None`/120 (1x) on compositors that don't support the protocols, so
+    // the overlay still renders, just not HiDPI-sharp.
+    viewporter: Option<WpViewporter>,
+    fractional_scale_manager: Option<WpFractionalScaleManagerV1>,
+    viewport: Option<WpViewport>,
+    fractional_scale: Option<WpFractionalScaleV1>,
+    scale_120: u32,
+
+    // Move-mode drag tracking: `move_mode` gates whether pointer motion
+    // repositions the surface at all; `drag_last` is the previous motion
+    // event's surface-local position while `BTN_LEFT` is held, `None` right
+    // after a press so the first sample only primes the delta instead of
+    // jumping the window.
+    move_mode: bool,
+    dragging: bool,
+    drag_last: Option<(f64, f64)>,
+
+    // Frame-callback pacing: `commit_frame` requests a `wl_callback` before
+    // committing and clears `frame_ready` until its `Done` event arrives, so
+    // `WaylandOverlay::run` never paints faster than the compositor consumes
+    // frames. `dirty` is the complementary half: set by `request_redraw`,
+    // cleared once a frame actually paints, so an idle overlay (frame-ready
+    // but nothing new to show) doesn't repaint an unchanged buffer either.
+    // Both start `true` so the very first configure paints immediately.
+    frame_ready: bool,
+    dirty: bool,
 
     // Pixel buffer (RGBA format for rendering, converted to ARGB for Wayland)
     pixel_data: Vec<u8>,
-    shm_data: Option<ShmBuffer>,
+
+    // Ping-pong pair of SHM-backed `wl_buffer`s. `commit_frame` writes into
+    // whichever slot isn't `busy` (attached and not yet released by the
+    // compositor), so a slow compositor reading the last-attached buffer
+    // never races a write against that same memory.
+    slots: Vec<BufferSlot>,
 }
 
 struct ShmBuffer {
@@ -56,35 +145,176 @@ struct ShmBuffer {
     size: usize,
 }
 
-// SAFETY: We only access shm_data from the main thread
+// SAFETY: We only access the mapped memory from the main thread
 unsafe impl Send for ShmBuffer {}
 
+struct BufferSlot {
+    wl_buffer: WlBuffer,
+    shm: ShmBuffer,
+    /// Set on attach, cleared when `Dispatch<WlBuffer, ()>` sees this
+    /// slot's `Release` event.
+    busy: bool,
+}
+
+/// Collected piecemeal from a `wl_output`'s `Geometry`/`Mode`/`Name` events,
+/// which arrive as a batch but not necessarily in a fixed order.
+#[derive(Debug, Clone, Default)]
+struct OutputInfo {
+    name: Option<String>,
+    description: Option<String>,
+    /// `(x, y)` position in the compositor's global space, from `Geometry`.
+    geometry: Option<(i32, i32)>,
+    /// `(width, height)` of the current mode, from `Mode`.
+    mode: Option<(i32, i32)>,
+}
+
 impl WaylandState {
-    fn new(width: u32, height: u32) -> Self {
+    fn new(width: u32, height: u32, x: i32, y: i32, namespace: String, target_output: Option<String>, click_through: bool) -> Self {
         let pixel_count = (width * height) as usize;
         Self {
             running: true,
             configured: false,
             width,
             height,
+            x,
+            y,
             compositor: None,
+            layer_shell: None,
             surface: None,
             layer_surface: None,
             shm: None,
-            buffer: None,
+            seat: None,
+            pointer: None,
+            keyboard: None,
+            ctrl_held: false,
+            alt_held: false,
+            shift_held: false,
+            pending_events: Vec::new(),
+            namespace,
+            click_through,
+            target_output,
+            bound_output: None,
+            outputs: Vec::new(),
+            viewporter: None,
+            fractional_scale_manager: None,
+            viewport: None,
+            fractional_scale: None,
+            scale_120: SCALE_120_DENOMINATOR,
+            move_mode: false,
+            dragging: false,
+            drag_last: None,
+            frame_ready: true,
+            dirty: true,
             pixel_data: vec![0u8; pixel_count * 4],
-            shm_data: None,
+            slots: Vec::new(),
         }
     }
 
-    fn create_shm_buffer(&mut self, qh: &QueueHandle<WaylandState>) {
-        let shm = match &self.shm {
-            Some(s) => s,
-            None => return,
+    /// Move the surface to `(x, y)` via the layer-surface margin, the same
+    /// mechanism `WaylandOverlay::set_position` uses.
+    fn set_position(&mut self, x: i32, y: i32) {
+        self.x = x;
+        self.y = y;
+        if let Some(layer_surface) = &self.layer_surface {
+            layer_surface.set_margin(y, 0, 0, x);
+        }
+        if let Some(surface) = &self.surface {
+            surface.commit();
+        }
+    }
+
+    /// Known `wl_output` matching `target_output` by name, if its `Name`
+    /// event has arrived yet.
+    fn matching_output(&self) -> Option<WlOutput> {
+        let target = self.target_output.as_deref()?;
+        self.outputs
+            .iter()
+            .find(|(_, info)| info.name.as_deref() == Some(target))
+            .map(|(output, _)| output.clone())
+    }
+
+    /// Tear down the current surface (if any, e.g. when re-anchoring) and
+    /// recreate it pinned to `output` (or to the compositor's choice, if
+    /// `None`), reapplying the layer-surface configuration `new()` sets up.
+    /// Used both for the initial surface creation and to re-anchor if a
+    /// matching output's `Name` event arrives only after startup.
+    fn recreate_surface_for_output(&mut self, qh: &QueueHandle<WaylandState>, output: Option<WlOutput>) {
+        let (Some(compositor), Some(layer_shell)) = (&self.compositor, &self.layer_shell) else {
+            return;
         };
 
-        let stride = self.width * 4;
-        let size = (stride * self.height) as usize;
+        // wp_viewport/wp_fractional_scale_v1 are per-surface and must go
+        // before the surface they were created against.
+        if let Some(viewport) = self.viewport.take() {
+            viewport.destroy();
+        }
+        if let Some(fractional_scale) = self.fractional_scale.take() {
+            fractional_scale.destroy();
+        }
+        if let Some(old) = self.layer_surface.take() {
+            old.destroy();
+        }
+        if let Some(old) = self.surface.take() {
+            old.destroy();
+        }
+
+        let surface = compositor.create_surface(qh, ());
+        let layer_surface = layer_shell.get_layer_surface(
+            &surface,
+            output.as_ref(),
+            wayland_protocols_wlr::layer_shell::v1::client::zwlr_layer_shell_v1::Layer::Overlay,
+            self.namespace.clone(),
+            qh,
+            (),
+        );
+
+        if let Some(viewporter) = &self.viewporter {
+            self.viewport = Some(viewporter.get_viewport(&surface, qh, ()));
+        }
+        if let Some(manager) = &self.fractional_scale_manager {
+            self.fractional_scale = Some(manager.get_fractional_scale(&surface, qh, ()));
+        }
+
+        if self.click_through {
+            let region = compositor.create_region(qh, ());
+            surface.set_input_region(Some(&region));
+        }
+
+        layer_surface.set_anchor(Anchor::Top | Anchor::Left);
+        layer_surface.set_margin(self.y, 0, 0, self.x);
+        layer_surface.set_keyboard_interactivity(KeyboardInteractivity::None);
+        layer_surface.set_size(self.width, self.height);
+        surface.commit();
+
+        self.surface = Some(surface);
+        self.layer_surface = Some(layer_surface);
+        self.bound_output = output;
+        self.configured = false;
+        self.update_viewport_destination();
+    }
+
+    /// Physical pixel dimensions of the backing buffer: logical size scaled
+    /// by `scale_120`, so a 1.5x compositor (`scale_120 == 180`) gets a 50%
+    /// larger buffer that `wp_viewport` then maps back down to logical size.
+    fn physical_size(&self) -> (u32, u32) {
+        let scale = |logical: u32| (logical * self.scale_120) / SCALE_120_DENOMINATOR;
+        (scale(self.width), scale(self.height))
+    }
+
+    /// Tell the compositor to map the (possibly larger) physical buffer down
+    /// to the logical `width`x`height` rectangle. A no-op if `wp_viewporter`
+    /// isn't bound.
+    fn update_viewport_destination(&self) {
+        if let Some(viewport) = &self.viewport {
+            viewport.set_destination(self.width as i32, self.height as i32);
+        }
+    }
+
+    /// Allocate one SHM-backed `wl_buffer` at the current physical size.
+    fn create_buffer_slot(&self, qh: &QueueHandle<WaylandState>, width: u32, height: u32) -> Option<BufferSlot> {
+        let shm = self.shm.as_ref()?;
+        let stride = width * 4;
+        let size = (stride * height) as usize;
 
         // Create anonymous shared memory
         let fd = memfd_create(c"baras-overlay-buffer", MemfdFlags::CLOEXEC)
@@ -105,31 +335,55 @@ impl WaylandState {
             .expect("Failed to mmap")
         };
 
-        self.shm_data = Some(ShmBuffer {
-            ptr: ptr as *mut u8,
-            size,
-        });
-
-        // Create wayland shm pool and buffer
         let pool = shm.create_pool(fd.as_fd(), size as i32, qh, ());
-        self.buffer = Some(pool.create_buffer(
-            0,
-            self.width as i32,
-            self.height as i32,
-            stride as i32,
-            Format::Argb8888,
-            qh,
-            (),
-        ));
+        let wl_buffer = pool.create_buffer(0, width as i32, height as i32, stride as i32, Format::Argb8888, qh, ());
+
+        Some(BufferSlot {
+            wl_buffer,
+            shm: ShmBuffer {
+                ptr: ptr as *mut u8,
+                size,
+            },
+            busy: false,
+        })
     }
 
-    fn copy_pixels_to_shm(&mut self) {
-        let shm = match &self.shm_data {
-            Some(s) => s,
-            None => return,
+    /// (Re)allocate both ping-pong slots at the current physical size, e.g.
+    /// on startup, on `set_size`, or when `PreferredScale` changes.
+    fn create_shm_buffers(&mut self, qh: &QueueHandle<WaylandState>) {
+        if self.shm.is_none() {
+            return;
+        }
+
+        let (width, height) = self.physical_size();
+
+        // The renderer draws into `pixel_data` at physical resolution, so it
+        // must be resized to match whenever the physical size changes.
+        self.pixel_data.resize((width * height) as usize * 4, 0);
+
+        self.slots = (0..2)
+            .filter_map(|_| self.create_buffer_slot(qh, width, height))
+            .collect();
+
+        self.update_viewport_destination();
+    }
+
+    /// Copy `pixel_data` into a free slot and attach+commit it. Skips the
+    /// frame entirely if both slots are still busy (the compositor hasn't
+    /// released either of the last two attached buffers yet), rather than
+    /// racing a write against memory the compositor may still be reading.
+    fn commit_frame(&mut self, qh: &QueueHandle<WaylandState>) {
+        let Some(surface) = self.surface.clone() else {
+            return;
+        };
+        let Some(slot_index) = self.slots.iter().position(|slot| !slot.busy) else {
+            return;
         };
 
-        let shm_slice = unsafe { std::slice::from_raw_parts_mut(shm.ptr, shm.size) };
+        let (width, height) = self.physical_size();
+        let slot = &mut self.slots[slot_index];
+
+        let shm_slice = unsafe { std::slice::from_raw_parts_mut(slot.shm.ptr, slot.shm.size) };
 
         // Convert RGBA to BGRA (Wayland ARGB8888 is BGRA in little-endian)
         for (i, chunk) in self.pixel_data.chunks(4).enumerate() {
@@ -141,14 +395,18 @@ impl WaylandState {
                 shm_slice[offset + 3] = chunk[3]; // A
             }
         }
-    }
 
-    fn commit_frame(&self) {
-        if let (Some(surface), Some(buffer)) = (&self.surface, &self.buffer) {
-            surface.attach(Some(buffer), 0, 0);
-            surface.damage_buffer(0, 0, self.width as i32, self.height as i32);
-            surface.commit();
-        }
+        slot.busy = true;
+        surface.attach(Some(&slot.wl_buffer), 0, 0);
+        surface.damage_buffer(0, 0, width as i32, height as i32);
+
+        // Request the next frame callback before committing, per
+        // wl_surface's documented ordering: the compositor only fires Done
+        // once it's ready for another frame, which is what paces `run`.
+        surface.frame(qh, ());
+        self.frame_ready = false;
+
+        surface.commit();
     }
 }
 
@@ -162,7 +420,16 @@ impl OverlayPlatform for WaylandOverlay {
                 .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
 
         let qh = event_queue.handle();
-        let mut state = WaylandState::new(config.width, config.height);
+        let target_output = config.output.clone();
+        let mut state = WaylandState::new(
+            config.width,
+            config.height,
+            config.x,
+            config.y,
+            config.namespace.clone(),
+            target_output,
+            config.click_through,
+        );
 
         // Bind globals
         let _registry = connection.display().get_registry(&qh, ());
@@ -181,36 +448,46 @@ impl OverlayPlatform for WaylandOverlay {
 
         state.shm = Some(shm);
 
-        // Create surface
-        let surface = compositor.create_surface(&qh, ());
-        let layer_surface = layer_shell.get_layer_surface(
-            &surface,
-            None,
-            wayland_protocols_wlr::layer_shell::v1::client::zwlr_layer_shell_v1::Layer::Overlay,
-            config.namespace.clone(),
-            &qh,
-            (),
-        );
+        // wl_seat is only needed for input (move-mode dragging and key
+        // presses), not for rendering the overlay, so a compositor without one is a warning rather than
+        // a hard failure like the globals above.
+        match globals.bind::<WlSeat, _, _>(&qh, 1..=9, ()) {
+            Ok(seat) => state.seat = Some(seat),
+            Err(_) => eprintln!("wl_seat unavailable, move-mode dragging will not work"),
+        }
 
-        // Set up click-through if requested
-        if config.click_through {
-            let region = compositor.create_region(&qh, ());
-            surface.set_input_region(Some(&region));
+        // wp_viewporter and wp_fractional_scale_manager_v1 are likewise
+        // HiDPI-sharpness extras, not rendering requirements: without them
+        // the overlay just renders at 1x on a scaled compositor.
+        state.viewporter = globals.bind(&qh, 1..=1, ()).ok();
+        state.fractional_scale_manager = globals.bind(&qh, 1..=1, ()).ok();
+        if state.viewporter.is_none() || state.fractional_scale_manager.is_none() {
+            eprintln!("wp_viewporter/wp_fractional_scale_manager_v1 unavailable, overlay will render at 1x");
         }
 
-        // Configure layer surface
-        layer_surface.set_anchor(Anchor::Top | Anchor::Left);
-        layer_surface.set_margin(config.y, 0, 0, config.x);
-        layer_surface.set_keyboard_interactivity(KeyboardInteractivity::None);
-        layer_surface.set_size(config.width, config.height);
-        surface.commit();
+        // Bind every wl_output currently advertised, then roundtrip so their
+        // Geometry/Mode/Name events have arrived by the time we decide which
+        // output (if any) to pin the surface to.
+        let output_globals: Vec<(u32, u32)> = globals.contents().with_list(|list| {
+            list.iter()
+                .filter(|global| global.interface == "wl_output")
+                .map(|global| (global.name, global.version))
+                .collect()
+        });
+        for (name, version) in output_globals {
+            let output: WlOutput = globals.registry().bind(name, version.min(4), &qh, ());
+            state.outputs.push((output, OutputInfo::default()));
+        }
+        let _ = event_queue.roundtrip(&mut state);
 
         state.compositor = Some(compositor);
-        state.surface = Some(surface);
-        state.layer_surface = Some(layer_surface);
+        state.layer_shell = Some(layer_shell);
+
+        let pin_to = state.matching_output();
+        state.recreate_surface_for_output(&qh, pin_to);
 
         // Create shared memory buffer
-        state.create_shm_buffer(&qh);
+        state.create_shm_buffers(&qh);
 
         Ok(Self {
             config,
@@ -232,12 +509,7 @@ impl OverlayPlatform for WaylandOverlay {
     fn set_position(&mut self, x: i32, y: i32) {
         self.config.x = x;
         self.config.y = y;
-        if let Some(layer_surface) = &self.state.layer_surface {
-            layer_surface.set_margin(y, 0, 0, x);
-        }
-        if let Some(surface) = &self.state.surface {
-            surface.commit();
-        }
+        self.state.set_position(x, y);
     }
 
     fn set_size(&mut self, width: u32, height: u32) {
@@ -250,12 +522,11 @@ impl OverlayPlatform for WaylandOverlay {
         self.state.width = width;
         self.state.height = height;
 
-        // Resize pixel buffer
-        let pixel_count = (width * height) as usize;
-        self.state.pixel_data.resize(pixel_count * 4, 0);
-
-        // Recreate shm buffer
-        self.state.create_shm_buffer(&self.qh);
+        // Recreate the shm buffer at the new physical size; this also
+        // resizes `pixel_data` and updates the viewport destination. The
+        // old buffer's contents don't carry over, so force a repaint.
+        self.state.create_shm_buffers(&self.qh);
+        self.state.dirty = true;
 
         if let Some(layer_surface) = &self.state.layer_surface {
             layer_surface.set_size(width, height);
@@ -278,19 +549,48 @@ impl OverlayPlatform for WaylandOverlay {
         }
     }
 
+    fn set_move_mode(&mut self, enabled: bool) {
+        self.state.move_mode = enabled;
+        self.state.dragging = false;
+        self.state.drag_last = None;
+    }
+
+    /// Current compositor-preferred scale (1.0 if `wp_fractional_scale_v1`
+    /// isn't bound), so the rendering layer can draw text/icons at the same
+    /// resolution the physical buffer was allocated at.
+    fn scale_factor(&self) -> f64 {
+        f64::from(self.state.scale_120) / f64::from(SCALE_120_DENOMINATOR)
+    }
+
+    /// Names of every `wl_output` whose `Name` event has arrived so far, for
+    /// populating a monitor picker in settings.
+    fn available_outputs(&self) -> Vec<String> {
+        self.state
+            .outputs
+            .iter()
+            .filter_map(|(_, info)| info.name.clone())
+            .collect()
+    }
+
+    /// Mark the overlay dirty so the next `frame_ready` wakeup in `run`
+    /// actually repaints it.
+    fn request_redraw(&mut self) {
+        self.state.dirty = true;
+    }
+
     fn pixel_buffer(&mut self) -> Option<&mut [u8]> {
         Some(&mut self.state.pixel_data)
     }
 
     fn commit(&mut self) {
-        self.state.copy_pixels_to_shm();
-        self.state.commit_frame();
+        self.state.commit_frame(&self.qh);
     }
 
-    fn poll_events(&mut self) -> bool {
+    fn poll_events(&mut self) -> Vec<InputEvent> {
         // Flush outgoing requests first
         if self.connection.flush().is_err() {
-            return false;
+            self.state.running = false;
+            return vec![InputEvent::CloseRequested];
         }
 
         // Read events from the socket (non-blocking via prepare_read)
@@ -301,10 +601,14 @@ impl OverlayPlatform for WaylandOverlay {
 
         // Dispatch pending events
         if self.event_queue.dispatch_pending(&mut self.state).is_err() {
-            return false;
+            self.state.running = false;
         }
 
-        self.state.running
+        let mut events = std::mem::take(&mut self.state.pending_events);
+        if !self.state.running {
+            events.push(InputEvent::CloseRequested);
+        }
+        events
     }
 
     fn run<F>(&mut self, mut render_callback: F)
@@ -317,7 +621,12 @@ impl OverlayPlatform for WaylandOverlay {
                 break;
             }
 
-            if self.state.configured {
+            // Only paint once the compositor says it's ready for another
+            // frame AND something has actually changed since the last one;
+            // an idle overlay between combat updates then costs nothing
+            // beyond dispatching whatever woke the blocking read.
+            if self.state.configured && self.state.frame_ready && self.state.dirty {
+                self.state.dirty = false;
                 render_callback(self);
             }
         }
@@ -411,10 +720,199 @@ impl Dispatch<WlShmPool, ()> for WaylandState {
 }
 
 impl Dispatch<WlBuffer, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        proxy: &WlBuffer,
+        event: wayland_client::protocol::wl_buffer::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        if let wayland_client::protocol::wl_buffer::Event::Release = event {
+            if let Some(slot) = state.slots.iter_mut().find(|slot| &slot.wl_buffer == proxy) {
+                slot.busy = false;
+            }
+        }
+    }
+}
+
+impl Dispatch<WlCallback, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlCallback,
+        event: wl_callback::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        if let wl_callback::Event::Done { .. } = event {
+            state.frame_ready = true;
+        }
+    }
+}
+
+impl Dispatch<WlOutput, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        proxy: &WlOutput,
+        event: wl_output::Event,
+        _data: &(),
+        _conn: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        {
+            let Some((_, info)) = state.outputs.iter_mut().find(|(output, _)| output == proxy) else {
+                return;
+            };
+
+            match event {
+                wl_output::Event::Geometry { x, y, .. } => info.geometry = Some((x, y)),
+                wl_output::Event::Mode { width, height, .. } => info.mode = Some((width, height)),
+                wl_output::Event::Name { name } => info.name = Some(name),
+                wl_output::Event::Description { description } => info.description = Some(description),
+                _ => return,
+            }
+        }
+
+        // A `Name` that now matches `target_output` and wasn't already
+        // bound (either this output appeared after startup, or its Name
+        // event simply hadn't arrived yet at surface-creation time) means
+        // the surface should re-anchor to it.
+        if let Some(matched) = state.matching_output() {
+            if state.bound_output.as_ref() != Some(&matched) {
+                state.recreate_surface_for_output(qh, Some(matched));
+            }
+        }
+    }
+}
+
+impl Dispatch<WlSeat, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        proxy: &WlSeat,
+        event: wl_seat::Event,
+        _data: &(),
+        _conn: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        if let wl_seat::Event::Capabilities {
+            capabilities: WEnum::Value(capabilities),
+        } = event
+        {
+            if capabilities.contains(wl_seat::Capability::Pointer) && state.pointer.is_none() {
+                state.pointer = Some(proxy.get_pointer(qh, ()));
+            }
+            if capabilities.contains(wl_seat::Capability::Keyboard) && state.keyboard.is_none() {
+                state.keyboard = Some(proxy.get_keyboard(qh, ()));
+            }
+        }
+    }
+}
+
+impl Dispatch<WlKeyboard, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlKeyboard,
+        event: wl_keyboard::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        let wl_keyboard::Event::Key {
+            key,
+            state: WEnum::Value(key_state),
+            ..
+        } = event
+        else {
+            return;
+        };
+        let pressed = key_state == wl_keyboard::KeyState::Pressed;
+
+        match input::classify_evdev_key(key) {
+            EvdevKey::Ctrl => state.ctrl_held = pressed,
+            EvdevKey::Alt => state.alt_held = pressed,
+            EvdevKey::Shift => state.shift_held = pressed,
+            EvdevKey::Named(code) if pressed => {
+                state.pending_events.push(InputEvent::KeyPress(Key {
+                    code: code.to_string(),
+                    ctrl: state.ctrl_held,
+                    alt: state.alt_held,
+                    shift: state.shift_held,
+                }));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<WlPointer, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlPointer,
+        event: wl_pointer::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        match event {
+            wl_pointer::Event::Button {
+                button,
+                state: WEnum::Value(button_state),
+                ..
+            } if button == BTN_LEFT => {
+                state.dragging = state.move_mode && button_state == wl_pointer::ButtonState::Pressed;
+                state.drag_last = None;
+            }
+            wl_pointer::Event::Motion {
+                surface_x,
+                surface_y,
+                ..
+            } if state.dragging => {
+                let (x, y) = (f64::from(surface_x), f64::from(surface_y));
+                if let Some((last_x, last_y)) = state.drag_last {
+                    let dx = (x - last_x).round() as i32;
+                    let dy = (y - last_y).round() as i32;
+                    if dx != 0 || dy != 0 {
+                        let (new_x, new_y) = (state.x + dx, state.y + dy);
+                        state.set_position(new_x, new_y);
+                    }
+                }
+                state.drag_last = Some((x, y));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<WpViewporter, ()> for WaylandState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WpViewporter,
+        _event: wayland_protocols::wp::viewporter::client::wp_viewporter::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WpViewport, ()> for WaylandState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WpViewport,
+        _event: wayland_protocols::wp::viewporter::client::wp_viewport::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WpFractionalScaleManagerV1, ()> for WaylandState {
     fn event(
         _state: &mut Self,
-        _proxy: &WlBuffer,
-        _event: wayland_client::protocol::wl_buffer::Event,
+        _proxy: &WpFractionalScaleManagerV1,
+        _event: wayland_protocols::wp::fractional_scale::v1::client::wp_fractional_scale_manager_v1::Event,
         _data: &(),
         _conn: &Connection,
         _qh: &QueueHandle<Self>,
@@ -422,6 +920,25 @@ impl Dispatch<WlBuffer, ()> for WaylandState {
     }
 }
 
+impl Dispatch<WpFractionalScaleV1, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        _proxy: &WpFractionalScaleV1,
+        event: wp_fractional_scale_v1::Event,
+        _data: &(),
+        _conn: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        if let wp_fractional_scale_v1::Event::PreferredScale { scale } = event {
+            if scale != state.scale_120 {
+                state.scale_120 = scale;
+                state.create_shm_buffers(qh);
+                state.dirty = true;
+            }
+        }
+    }
+}
+
 impl Dispatch<ZwlrLayerShellV1, ()> for WaylandState {
     fn event(
         _state: &mut Self,
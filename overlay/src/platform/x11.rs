@@ -0,0 +1,429 @@
+//! X11 platform implementation using an override-redirect ARGB window
+//!
+//! This provides overlay windows on X11 sessions (and XWayland, for
+//! compositors the wlr-layer-shell-only [`super::wayland::WaylandOverlay`]
+//! can't attach to at all, like GNOME Wayland). The window is
+//! override-redirect, so it bypasses window-manager reparenting/decoration
+//! and stacking entirely, on a 32-bit TrueColor ("ARGB") visual so
+//! `pixel_data`'s alpha channel actually composites. It's tagged
+//! `_NET_WM_WINDOW_TYPE_NOTIFICATION` for window managers/compositors that
+//! still honor type hints on override-redirect windows, and click-through is
+//! implemented with the XShape extension's input region rather than
+//! anything layer-shell-specific.
+
+use x11rb::connection::Connection as _;
+use x11rb::protocol::randr::ConnectionExt as _;
+use x11rb::protocol::shape::{self, ConnectionExt as _};
+use x11rb::protocol::xproto::{ConnectionExt as _, *};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+use crate::input::{self, EvdevKey, InputEvent, Key};
+
+use super::{OverlayConfig, OverlayPlatform, PlatformError};
+
+/// X11 button index for the primary/left button, the button that drives
+/// move-mode dragging (the same role `BTN_LEFT` plays in `wayland.rs`).
+const BUTTON_LEFT: u8 = 1;
+
+/// `KeyPress` event `state` bits for Shift/Control/Alt, per the X protocol's
+/// `SetOfKeyButMask` encoding (Alt is conventionally `Mod1`).
+const SHIFT_MASK: u16 = 1 << 0;
+const CONTROL_MASK: u16 = 1 << 2;
+const MOD1_MASK: u16 = 1 << 3;
+
+/// X11 keycodes are evdev keycodes offset by 8, per the XKB evdev rules
+/// virtually every Linux X server ships with.
+const X11_EVDEV_OFFSET: u8 = 8;
+
+/// X11 overlay implementation
+pub struct X11Overlay {
+    config: OverlayConfig,
+    conn: RustConnection,
+    root: Window,
+    window: Window,
+    gc: Gcontext,
+
+    // Move-mode drag tracking, mirroring `WaylandState`'s fields: `move_mode`
+    // gates whether pointer motion repositions the window at all,
+    // `drag_last` is the previous motion event's root-relative position
+    // while the left button is held, `None` right after a press so the
+    // first sample only primes the delta instead of jumping the window.
+    move_mode: bool,
+    dragging: bool,
+    drag_last: Option<(i16, i16)>,
+
+    // RGBA pixel buffer the renderer draws into, converted to the X server's
+    // native byte order on `commit`, same division of labor as
+    // `WaylandState::pixel_data`/`commit_frame`.
+    pixel_data: Vec<u8>,
+    /// Scratch buffer `commit` converts `pixel_data` into before
+    /// `put_image`, reused across frames to avoid a per-frame allocation.
+    convert_scratch: Vec<u8>,
+
+    // X11 has no frame-callback analogue, so `run` just repaints whenever
+    // `dirty`, set by `request_redraw`, is true. Starts `true` so the first
+    // iteration paints the initial frame.
+    dirty: bool,
+
+    running: bool,
+
+    /// Key presses accumulated since the last `poll_events` call.
+    pending_events: Vec<InputEvent>,
+}
+
+impl X11Overlay {
+    /// First 32-bit `TrueColor` visual on `screen`, the depth/visual
+    /// combination that gives window contents a real alpha channel instead
+    /// of being composited as opaque.
+    fn find_argb_visual(screen: &Screen) -> Option<(u8, Visualid)> {
+        screen
+            .allowed_depths
+            .iter()
+            .find(|depth| depth.depth == 32)
+            .and_then(|depth| {
+                depth
+                    .visuals
+                    .iter()
+                    .find(|visual| visual.class == VisualClass::TRUE_COLOR)
+                    .map(|visual| (depth.depth, visual.visual_id))
+            })
+    }
+
+    fn intern_atom(conn: &RustConnection, name: &str) -> Result<Atom, PlatformError> {
+        conn.intern_atom(false, name.as_bytes())
+            .and_then(|cookie| cookie.reply())
+            .map(|reply| reply.atom)
+            .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))
+    }
+
+    /// Tag the window `_NET_WM_WINDOW_TYPE_NOTIFICATION` and
+    /// `_NET_WM_STATE_ABOVE`, for the handful of compositors/window managers
+    /// that still look at type/state hints on an override-redirect window.
+    fn set_window_hints(conn: &RustConnection, window: Window) -> Result<(), PlatformError> {
+        let net_wm_window_type = Self::intern_atom(conn, "_NET_WM_WINDOW_TYPE")?;
+        let net_wm_window_type_notification = Self::intern_atom(conn, "_NET_WM_WINDOW_TYPE_NOTIFICATION")?;
+        let net_wm_state = Self::intern_atom(conn, "_NET_WM_STATE")?;
+        let net_wm_state_above = Self::intern_atom(conn, "_NET_WM_STATE_ABOVE")?;
+
+        conn.change_property32(
+            PropMode::REPLACE,
+            window,
+            net_wm_window_type,
+            AtomEnum::ATOM,
+            &[net_wm_window_type_notification],
+        )
+        .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+
+        conn.change_property32(PropMode::REPLACE, window, net_wm_state, AtomEnum::ATOM, &[net_wm_state_above])
+            .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Top-left corner of the RandR output named `name`, if one is
+    /// currently connected with that name. Used to pin the overlay to a
+    /// monitor at creation time; unlike Wayland's `wl_output` advertisement,
+    /// RandR's monitor list is available synchronously so there's no
+    /// late-arriving-output case to handle.
+    fn output_origin(conn: &RustConnection, root: Window, name: &str) -> Option<(i16, i16)> {
+        let monitors = conn.randr_get_monitors(root, true).ok()?.reply().ok()?;
+        monitors.monitors.into_iter().find_map(|monitor| {
+            let atom_name = conn.get_atom_name(monitor.name).ok()?.reply().ok()?;
+            (String::from_utf8_lossy(&atom_name.name) == name).then_some((monitor.x, monitor.y))
+        })
+    }
+
+    /// Names of every currently connected RandR monitor.
+    fn list_outputs(conn: &RustConnection, root: Window) -> Vec<String> {
+        let Ok(monitors) = conn.randr_get_monitors(root, true).and_then(|cookie| cookie.reply()) else {
+            return Vec::new();
+        };
+        monitors
+            .monitors
+            .into_iter()
+            .filter_map(|monitor| {
+                let atom_name = conn.get_atom_name(monitor.name).ok()?.reply().ok()?;
+                Some(String::from_utf8_lossy(&atom_name.name).into_owned())
+            })
+            .collect()
+    }
+
+    /// Rebuild the XShape input region to either empty (click-through: every
+    /// button/motion event passes to whatever's behind the overlay) or the
+    /// full window (click-through disabled), mirroring
+    /// `WaylandOverlay::set_click_through`'s `wl_surface::set_input_region`.
+    fn apply_click_through(&self) {
+        let rects: &[Rectangle] = if self.config.click_through {
+            &[]
+        } else {
+            &[Rectangle {
+                x: 0,
+                y: 0,
+                width: self.config.width as u16,
+                height: self.config.height as u16,
+            }]
+        };
+
+        let _ = self.conn.shape_rectangles(
+            shape::SO::SET,
+            shape::SK::INPUT,
+            ClipOrdering::UNSORTED,
+            self.window,
+            0,
+            0,
+            rects,
+        );
+    }
+
+    /// Convert `pixel_data` (RGBA) into `convert_scratch` as BGRA, the byte
+    /// order a `ZPixmap` on a 32-bit depth visual expects on a
+    /// little-endian host, then push it to the window with `PutImage`. Same
+    /// RGBA->native-order conversion `WaylandState::commit_frame` does for
+    /// `wl_shm`'s `Argb8888`.
+    fn put_image(&mut self) {
+        self.convert_scratch.clear();
+        self.convert_scratch.extend(self.pixel_data.chunks_exact(4).flat_map(|chunk| {
+            [chunk[2], chunk[1], chunk[0], chunk[3]]
+        }));
+
+        let _ = self.conn.put_image(
+            ImageFormat::Z_PIXMAP,
+            self.window,
+            self.gc,
+            self.config.width as u16,
+            self.config.height as u16,
+            0,
+            0,
+            0,
+            32,
+            &self.convert_scratch,
+        );
+        let _ = self.conn.flush();
+    }
+}
+
+impl OverlayPlatform for X11Overlay {
+    fn new(config: OverlayConfig) -> Result<Self, PlatformError> {
+        let (conn, screen_num) =
+            RustConnection::connect(None).map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+        let screen = conn.setup().roots[screen_num].clone();
+        let root = screen.root;
+
+        let (depth, visual_id) = Self::find_argb_visual(&screen)
+            .ok_or_else(|| PlatformError::UnsupportedFeature("32-bit TrueColor visual".to_string()))?;
+
+        conn.extension_information(shape::X11_EXTENSION_NAME)
+            .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?
+            .ok_or_else(|| PlatformError::UnsupportedFeature("XShape".to_string()))?;
+
+        let colormap = conn.generate_id().map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+        conn.create_colormap(ColormapAlloc::NONE, colormap, root, visual_id)
+            .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+
+        let (x, y) = config
+            .output
+            .as_deref()
+            .and_then(|name| Self::output_origin(&conn, root, name))
+            .map(|(ox, oy)| (ox + config.x as i16, oy + config.y as i16))
+            .unwrap_or((config.x as i16, config.y as i16));
+
+        let window = conn.generate_id().map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+        let aux = CreateWindowAux::new()
+            .override_redirect(1)
+            .colormap(colormap)
+            .border_pixel(0)
+            .background_pixel(0)
+            .event_mask(
+                EventMask::BUTTON_PRESS
+                    | EventMask::BUTTON_RELEASE
+                    | EventMask::POINTER_MOTION
+                    | EventMask::STRUCTURE_NOTIFY
+                    | EventMask::KEY_PRESS,
+            );
+        conn.create_window(
+            depth,
+            window,
+            root,
+            x,
+            y,
+            config.width as u16,
+            config.height as u16,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            visual_id,
+            &aux,
+        )
+        .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+
+        Self::set_window_hints(&conn, window)?;
+
+        let gc = conn.generate_id().map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+        conn.create_gc(gc, window, &CreateGCAux::new())
+            .map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+
+        conn.map_window(window).map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+        conn.flush().map_err(|e| PlatformError::ConnectionFailed(e.to_string()))?;
+
+        let pixel_count = (config.width * config.height) as usize;
+        let mut overlay = Self {
+            config,
+            conn,
+            root,
+            window,
+            gc,
+            move_mode: false,
+            dragging: false,
+            drag_last: None,
+            pixel_data: vec![0u8; pixel_count * 4],
+            convert_scratch: Vec::with_capacity(pixel_count * 4),
+            dirty: true,
+            running: true,
+            pending_events: Vec::new(),
+        };
+        overlay.apply_click_through();
+        Ok(overlay)
+    }
+
+    fn width(&self) -> u32 {
+        self.config.width
+    }
+
+    fn height(&self) -> u32 {
+        self.config.height
+    }
+
+    fn set_position(&mut self, x: i32, y: i32) {
+        self.config.x = x;
+        self.config.y = y;
+        let aux = ConfigureWindowAux::new().x(x).y(y);
+        let _ = self.conn.configure_window(self.window, &aux);
+        let _ = self.conn.flush();
+    }
+
+    fn set_size(&mut self, width: u32, height: u32) {
+        if width == self.config.width && height == self.config.height {
+            return;
+        }
+
+        self.config.width = width;
+        self.config.height = height;
+        self.pixel_data.resize((width * height) as usize * 4, 0);
+        self.dirty = true;
+
+        let aux = ConfigureWindowAux::new().width(width).height(height);
+        let _ = self.conn.configure_window(self.window, &aux);
+        let _ = self.conn.flush();
+        self.apply_click_through();
+    }
+
+    fn set_click_through(&mut self, enabled: bool) {
+        self.config.click_through = enabled;
+        self.apply_click_through();
+        let _ = self.conn.flush();
+    }
+
+    fn set_move_mode(&mut self, enabled: bool) {
+        self.move_mode = enabled;
+        self.dragging = false;
+        self.drag_last = None;
+    }
+
+    /// X11 has no per-window notion of fractional scaling analogous to
+    /// `wp_fractional_scale_v1`, so this always reports 1x.
+    fn scale_factor(&self) -> f64 {
+        1.0
+    }
+
+    /// Names of every currently connected RandR monitor, for populating a
+    /// monitor picker.
+    fn available_outputs(&self) -> Vec<String> {
+        Self::list_outputs(&self.conn, self.root)
+    }
+
+    /// Mark the overlay dirty so the next `run` iteration repaints it.
+    fn request_redraw(&mut self) {
+        self.dirty = true;
+    }
+
+    fn pixel_buffer(&mut self) -> Option<&mut [u8]> {
+        Some(&mut self.pixel_data)
+    }
+
+    fn commit(&mut self) {
+        self.put_image();
+    }
+
+    fn poll_events(&mut self) -> Vec<InputEvent> {
+        while let Ok(Some(event)) = self.conn.poll_for_event() {
+            self.handle_event(event);
+        }
+        let mut events = std::mem::take(&mut self.pending_events);
+        if !self.running {
+            events.push(InputEvent::CloseRequested);
+        }
+        events
+    }
+
+    fn run<F>(&mut self, mut render_callback: F)
+    where
+        F: FnMut(&mut Self),
+    {
+        while self.running {
+            let Ok(event) = self.conn.wait_for_event() else {
+                break;
+            };
+            self.handle_event(event);
+            while let Ok(Some(event)) = self.conn.poll_for_event() {
+                self.handle_event(event);
+            }
+            if self.running && self.dirty {
+                self.dirty = false;
+                render_callback(self);
+            }
+        }
+    }
+}
+
+impl X11Overlay {
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::ButtonPress(ev) if ev.detail == BUTTON_LEFT => {
+                self.dragging = self.move_mode;
+                self.drag_last = None;
+            }
+            Event::ButtonRelease(ev) if ev.detail == BUTTON_LEFT => {
+                self.dragging = false;
+                self.drag_last = None;
+            }
+            Event::MotionNotify(ev) if self.dragging => {
+                let (x, y) = (ev.root_x, ev.root_y);
+                if let Some((last_x, last_y)) = self.drag_last {
+                    let dx = i32::from(x - last_x);
+                    let dy = i32::from(y - last_y);
+                    if dx != 0 || dy != 0 {
+                        self.set_position(self.config.x + dx, self.config.y + dy);
+                    }
+                }
+                self.drag_last = Some((x, y));
+            }
+            Event::KeyPress(ev) => {
+                if let Some(code) = (ev.detail as u32).checked_sub(X11_EVDEV_OFFSET as u32) {
+                    if let EvdevKey::Named(name) = input::classify_evdev_key(code) {
+                        self.pending_events.push(InputEvent::KeyPress(Key {
+                            code: name.to_string(),
+                            ctrl: ev.state & CONTROL_MASK != 0,
+                            alt: ev.state & MOD1_MASK != 0,
+                            shift: ev.state & SHIFT_MASK != 0,
+                        }));
+                    }
+                }
+            }
+            Event::DestroyNotify(_) => {
+                self.running = false;
+            }
+            _ => {}
+        }
+    }
+}
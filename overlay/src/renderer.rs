@@ -0,0 +1,49 @@
+//! Software pixel-buffer renderer
+//!
+//! Draws icons (see [`Renderer::draw_image`]) straight into the RGBA8 buffer
+//! platform backends hand back from `pixel_buffer()`. This type has no shape
+//! or text drawing methods.
+
+use crate::icon::{self, IconCache, IconHandle};
+use std::path::Path;
+
+/// Software renderer drawing into a platform's RGBA8 pixel buffer.
+pub struct Renderer {
+    icons: IconCache,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self { icons: IconCache::new() }
+    }
+
+    /// Decode (or reuse a cached decode of) the PNG at `path`, keyed by
+    /// `key`, for use with [`Renderer::draw_image`].
+    pub fn load_icon(&mut self, key: &str, path: &Path) -> Option<IconHandle> {
+        self.icons.load(key, path)
+    }
+
+    /// Blit `icon` into `buffer` at `(x, y)`, downscaled to `target_w x
+    /// target_h` with nearest-neighbor sampling and alpha-over composited,
+    /// clamped to the buffer's bounds.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_image(
+        &self,
+        buffer: &mut [u8],
+        buffer_width: u32,
+        buffer_height: u32,
+        icon: &IconHandle,
+        x: f32,
+        y: f32,
+        target_w: u32,
+        target_h: u32,
+    ) {
+        icon::composite(buffer, buffer_width, buffer_height, icon, x, y, target_w, target_h);
+    }
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
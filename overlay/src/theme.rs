@@ -0,0 +1,215 @@
+//! Loadable, hot-reloadable color theme for overlay rendering
+//!
+//! Every color in [`crate::manager::MeterOverlay`] and
+//! [`crate::widgets::ProgressBar`] used to come from hardcoded
+//! `widgets::colors::*` calls, so users couldn't restyle them without
+//! recompiling. [`Theme`] is a serde-derived TOML document (format inspired
+//! by yazi's `config/theme/color.rs`) with hex-string colors
+//! (`#RRGGBB`/`#RRGGBBAA`) that fall back to `widgets::colors::*`'s current
+//! defaults when a key is missing, plus per-class (keyed by
+//! `Entity.class_id`) and per-metric overrides. A color string that fails
+//! to parse fails the whole file, and [`Theme::load_from`] then returns
+//! [`Theme::defaults`].
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Deserialize;
+use tiny_skia::Color;
+
+use crate::input::Keymap;
+use crate::widgets::colors;
+
+/// Debounce window for coalescing rapid successive writes to the theme file
+/// (editors often emit several modify events per save), mirroring
+/// `core::context::ConfigWatcher`'s debounce.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// A `#RRGGBB` or `#RRGGBBAA` hex color, deserialized from its string form.
+#[derive(Debug, Clone, Copy)]
+struct HexColor(Color);
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse_hex_color(&s)
+            .map(HexColor)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid hex color {s:?}, expected #RRGGBB or #RRGGBBAA")))
+    }
+}
+
+fn parse_hex_color(s: &str) -> Option<Color> {
+    let hex = s.strip_prefix('#')?;
+    let byte = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    let (r, g, b) = (byte(0)?, byte(2)?, byte(4)?);
+    let a = match hex.len() {
+        6 => 255,
+        8 => byte(6)?,
+        _ => return None,
+    };
+    Some(Color::from_rgba8(r, g, b, a))
+}
+
+/// Color palette for the `MeterOverlay`/`ProgressBar` render paths, loaded
+/// from a TOML file. Any key that's missing falls back to the matching
+/// `widgets::colors::*` default, so a theme file only needs to specify the
+/// colors it wants to change. A malformed color, in any table, fails the
+/// parse of the whole file rather than of that one key.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    bar_fill: Option<HexColor>,
+    bar_bg: Option<HexColor>,
+    title_text: Option<HexColor>,
+    separator: Option<HexColor>,
+    resize_grip: Option<HexColor>,
+    /// Bar fill for the synthetic "Others" row `MeterOverlay::render` draws
+    /// when entries are folded past `max_rows`.
+    others_bar_fill: Option<HexColor>,
+    /// Overrides keyed by `Entity.class_id` (as a string, since TOML table
+    /// keys must be strings), e.g. `[class_colors]` `"1"` `= "#RRGGBB"`.
+    #[serde(default)]
+    class_colors: HashMap<String, HexColor>,
+    /// Overrides keyed by metric name (`"dps"`, `"hps"`, ...), e.g.
+    /// `[metric_colors]` `dps = "#RRGGBB"`.
+    #[serde(default)]
+    metric_colors: HashMap<String, HexColor>,
+    /// User overrides for `Keymap::defaults`, as `"ctrl+r" = "reset_encounter"`
+    /// entries in a `[keybindings]` table. Unknown chords/actions are ignored
+    /// rather than rejecting the whole file (unlike a malformed color).
+    #[serde(default)]
+    keybindings: HashMap<String, String>,
+}
+
+impl Theme {
+    /// The built-in palette: every color resolves to its `widgets::colors::*`
+    /// default.
+    pub fn defaults() -> Self {
+        Self::default()
+    }
+
+    /// Load from `path`, falling back to [`Theme::defaults`] if the file
+    /// doesn't exist or fails to parse.
+    pub fn load_from(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn bar_fill(&self) -> Color {
+        self.bar_fill.map(|c| c.0).unwrap_or_else(colors::dps_bar_fill)
+    }
+
+    pub fn bar_bg(&self) -> Color {
+        self.bar_bg.map(|c| c.0).unwrap_or_else(colors::dps_bar_bg)
+    }
+
+    pub fn title_text(&self) -> Color {
+        self.title_text.map(|c| c.0).unwrap_or_else(colors::white)
+    }
+
+    pub fn separator(&self) -> Color {
+        self.separator.map(|c| c.0).unwrap_or_else(colors::white)
+    }
+
+    pub fn resize_grip(&self) -> Color {
+        self.resize_grip.map(|c| c.0).unwrap_or_else(colors::white)
+    }
+
+    pub fn others_bar_fill(&self) -> Color {
+        self.others_bar_fill.map(|c| c.0).unwrap_or_else(colors::label_dim)
+    }
+
+    /// Override for `class_id` (`Entity.class_id`), if the theme defines one.
+    pub fn class_color(&self, class_id: i64) -> Option<Color> {
+        self.class_colors.get(&class_id.to_string()).map(|c| c.0)
+    }
+
+    /// Override for a metric name (`"dps"`, `"hps"`, ...), if the theme
+    /// defines one.
+    pub fn metric_color(&self, metric: &str) -> Option<Color> {
+        self.metric_colors.get(metric).map(|c| c.0)
+    }
+
+    /// The active keymap: `Keymap::defaults` with this theme's
+    /// `keybindings` overrides applied.
+    pub fn keymap(&self) -> Keymap {
+        Keymap::with_overrides(&self.keybindings)
+    }
+}
+
+/// Result of a debounced theme-file reload.
+pub enum ThemeEvent {
+    /// The theme file was re-parsed (or fell back to defaults) and should
+    /// replace the current one.
+    Reloaded(Theme),
+}
+
+/// Watches a theme file for changes and hands back a debounced reload.
+///
+/// Mirrors `core::context::ConfigWatcher`'s single-file debounce, but kept
+/// local to this crate (rather than reusing `core`'s `DirectoryWatcher`)
+/// since `overlay` doesn't depend on `core`, and `DirectoryWatcher`'s event
+/// classification is specific to combat-log file naming. Polled rather than
+/// awaited, matching the platform `run` loops' synchronous dirty-flag style.
+pub struct ThemeWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+    path: PathBuf,
+    pending_since: Option<Instant>,
+}
+
+impl ThemeWatcher {
+    pub fn new(path: &Path) -> notify::Result<Self> {
+        let (tx, rx) = mpsc::channel();
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                let _ = tx.send(res);
+            },
+            Config::default(),
+        )?;
+
+        // Watch the parent directory rather than the file itself so we keep
+        // receiving events across editors that replace the file on save.
+        let watch_target = path.parent().unwrap_or(path);
+        watcher.watch(watch_target, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            path: path.to_path_buf(),
+            pending_since: None,
+        })
+    }
+
+    /// Non-blocking; call once per render/event-loop iteration. Returns a
+    /// reload once writes to the theme file have settled for [`DEBOUNCE`].
+    pub fn poll(&mut self) -> Option<ThemeEvent> {
+        while let Ok(Ok(event)) = self.rx.try_recv() {
+            if self.touches_theme(&event) {
+                self.pending_since = Some(Instant::now());
+            }
+        }
+
+        let since = self.pending_since?;
+        if since.elapsed() < DEBOUNCE {
+            return None;
+        }
+
+        self.pending_since = None;
+        Some(ThemeEvent::Reloaded(Theme::load_from(&self.path)))
+    }
+
+    fn touches_theme(&self, event: &Event) -> bool {
+        matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_))
+            && event.paths.iter().any(|p| p == &self.path)
+    }
+}
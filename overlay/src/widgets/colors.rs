@@ -0,0 +1,35 @@
+//! Default color palette for overlay widgets
+//!
+//! These are the built-in colors every widget falls back to when no
+//! [`crate::theme::Theme`] override applies. Plain functions rather than
+//! `const`s since `tiny_skia::Color` has no `const fn` constructor.
+
+use tiny_skia::Color;
+
+pub fn white() -> Color {
+    Color::from_rgba8(255, 255, 255, 255)
+}
+
+pub fn transparent() -> Color {
+    Color::from_rgba8(0, 0, 0, 0)
+}
+
+pub fn overlay_bg() -> Color {
+    Color::from_rgba8(20, 20, 24, 200)
+}
+
+pub fn dps_bar_bg() -> Color {
+    Color::from_rgba8(40, 40, 46, 220)
+}
+
+pub fn dps_bar_fill() -> Color {
+    Color::from_rgba8(66, 133, 244, 255)
+}
+
+pub fn label_dim() -> Color {
+    Color::from_rgba8(180, 180, 190, 200)
+}
+
+pub fn text_shadow() -> Color {
+    Color::from_rgba8(0, 0, 0, 160)
+}
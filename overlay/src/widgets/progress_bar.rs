@@ -3,17 +3,21 @@
 use tiny_skia::Color;
 
 use crate::frame::OverlayFrame;
-use crate::renderer::colors;
+use crate::theme::Theme;
 
 /// A horizontal progress bar with label and value
+///
+/// `fill_color`/`bg_color`/`text_color` are explicit overrides; when left
+/// `None`, `render` resolves them from the `Theme` passed in, so a bar only
+/// needs to specify the colors it wants to pin regardless of the active theme.
 #[derive(Debug, Clone)]
 pub struct ProgressBar {
     pub label: String,
     pub value: f64,
     pub max_value: f64,
-    pub fill_color: Color,
-    pub bg_color: Color,
-    pub text_color: Color,
+    pub fill_color: Option<Color>,
+    pub bg_color: Option<Color>,
+    pub text_color: Option<Color>,
     pub show_value: bool,
 }
 
@@ -23,25 +27,25 @@ impl ProgressBar {
             label: label.into(),
             value,
             max_value,
-            fill_color: colors::dps_bar_fill(),
-            bg_color: colors::dps_bar_bg(),
-            text_color: colors::white(),
+            fill_color: None,
+            bg_color: None,
+            text_color: None,
             show_value: true,
         }
     }
 
     pub fn with_fill_color(mut self, color: Color) -> Self {
-        self.fill_color = color;
+        self.fill_color = Some(color);
         self
     }
 
     pub fn with_bg_color(mut self, color: Color) -> Self {
-        self.bg_color = color;
+        self.bg_color = Some(color);
         self
     }
 
     pub fn with_text_color(mut self, color: Color) -> Self {
-        self.text_color = color;
+        self.text_color = Some(color);
         self
     }
 
@@ -59,7 +63,9 @@ impl ProgressBar {
         }
     }
 
-    /// Render the progress bar to an OverlayFrame
+    /// Render the progress bar to an OverlayFrame, resolving any color left
+    /// unset via the builder from `theme`.
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &self,
         frame: &mut OverlayFrame,
@@ -69,20 +75,25 @@ impl ProgressBar {
         height: f32,
         font_size: f32,
         radius: f32,
+        theme: &Theme,
     ) {
+        let bg_color = self.bg_color.unwrap_or_else(|| theme.bar_bg());
+        let fill_color = self.fill_color.unwrap_or_else(|| theme.bar_fill());
+        let text_color = self.text_color.unwrap_or_else(|| theme.title_text());
+
         // Draw background
-        frame.fill_rounded_rect(x, y, width, height, radius, self.bg_color);
+        frame.fill_rounded_rect(x, y, width, height, radius, bg_color);
 
         // Draw fill
         let fill_width = width * self.progress();
         if fill_width > 0.0 {
-            frame.fill_rounded_rect(x, y, fill_width, height, radius, self.fill_color);
+            frame.fill_rounded_rect(x, y, fill_width, height, radius, fill_color);
         }
 
         // Draw label on the left
         let text_y = y + height / 2.0 + font_size / 3.0;
         let text_padding = 4.0 * frame.scale_factor();
-        frame.draw_text(&self.label, x + text_padding, text_y, font_size, self.text_color);
+        frame.draw_text(&self.label, x + text_padding, text_y, font_size, text_color);
 
         // Draw value on the right
         if self.show_value {
@@ -93,7 +104,7 @@ impl ProgressBar {
                 x + width - text_width - text_padding,
                 text_y,
                 font_size,
-                self.text_color,
+                text_color,
             );
         }
     }
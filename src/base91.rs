@@ -0,0 +1,95 @@
+//! basE91 encoding
+//!
+//! A denser alternative to base64 (~19% size overhead vs. ~33%) used to turn
+//! binary share-code payloads into a copy-pasteable ASCII string for
+//! Discord/forum posts. Implementation follows the reference basE91 bit
+//! accumulator algorithm.
+
+/// The standard 91-character basE91 alphabet, excluding `-`, `\`, and `'`
+/// since those tend to cause trouble when pasted into chat clients/forums.
+const ALPHABET: &[u8; 91] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&()*+,./:;<=>?@[]^_`{|}~\"";
+
+fn decode_table() -> [i8; 256] {
+    let mut table = [-1i8; 256];
+    for (i, &c) in ALPHABET.iter().enumerate() {
+        table[c as usize] = i as i8;
+    }
+    table
+}
+
+/// Encode arbitrary bytes into a basE91 string.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len() * 16 / 13 + 2);
+    let mut b: u32 = 0;
+    let mut n: u32 = 0;
+
+    for &byte in data {
+        b |= (byte as u32) << n;
+        n += 8;
+
+        if n > 13 {
+            let mut v = b & 8191;
+            if v > 88 {
+                b >>= 13;
+                n -= 13;
+            } else {
+                v = b & 16383;
+                b >>= 14;
+                n -= 14;
+            }
+            out.push(ALPHABET[(v % 91) as usize] as char);
+            out.push(ALPHABET[(v / 91) as usize] as char);
+        }
+    }
+
+    if n > 0 {
+        out.push(ALPHABET[(b % 91) as usize] as char);
+        if n > 7 || b > 90 {
+            out.push(ALPHABET[(b / 91) as usize] as char);
+        }
+    }
+
+    out
+}
+
+/// Decode a basE91 string back into bytes.
+///
+/// Returns `None` if the input contains characters outside the basE91
+/// alphabet.
+pub fn decode(s: &str) -> Option<Vec<u8>> {
+    let table = decode_table();
+    let mut out = Vec::with_capacity(s.len() * 13 / 16 + 2);
+    let mut b: u32 = 0;
+    let mut n: u32 = 0;
+    let mut v: i32 = -1;
+
+    for c in s.bytes() {
+        let d = table[c as usize];
+        if d < 0 {
+            return None;
+        }
+
+        if v < 0 {
+            v = d as i32;
+            continue;
+        }
+
+        v += d as i32 * 91;
+        b |= (v as u32) << n;
+        n += if (v & 8191) > 88 { 13 } else { 14 };
+
+        while n >= 8 {
+            out.push((b & 255) as u8);
+            b >>= 8;
+            n -= 8;
+        }
+
+        v = -1;
+    }
+
+    if v >= 0 {
+        out.push(((b | ((v as u32) << n)) & 255) as u8);
+    }
+
+    Some(out)
+}
@@ -0,0 +1,93 @@
+//! Export parsed combat log data to JSON/CSV for spreadsheet workflows
+//!
+//! Used by the `export` CLI subcommand to turn a `combat_*.txt` log into a
+//! flat file of per-event records, suitable for batch-processing a folder of
+//! logs without the overlay UI.
+
+use crate::event_models::CombatEvent;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Output format for the `export` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(ExportFormat::Json),
+            "csv" => Ok(ExportFormat::Csv),
+            other => Err(format!("unknown export format '{other}', expected json or csv")),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Json => write!(f, "json"),
+            ExportFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// Write `events` to `out` in the requested format.
+pub fn export_events(events: &[CombatEvent], format: ExportFormat, out: &Path) -> io::Result<()> {
+    let mut file = File::create(out)?;
+
+    match format {
+        ExportFormat::Json => write_json(&mut file, events),
+        ExportFormat::Csv => write_csv(&mut file, events),
+    }
+}
+
+fn write_json(file: &mut File, events: &[CombatEvent]) -> io::Result<()> {
+    writeln!(file, "[")?;
+    for (i, event) in events.iter().enumerate() {
+        let comma = if i + 1 == events.len() { "" } else { "," };
+        writeln!(
+            file,
+            "  {{\"line\": {}, \"source\": \"{}\", \"damage\": {}, \"heal\": {}}}{}",
+            event.line_number,
+            escape_json(&event.source_entity.name),
+            event.damage.unwrap_or(0),
+            event.heal.unwrap_or(0),
+            comma
+        )?;
+    }
+    writeln!(file, "]")
+}
+
+fn write_csv(file: &mut File, events: &[CombatEvent]) -> io::Result<()> {
+    writeln!(file, "line,source,damage,heal")?;
+    for event in events {
+        writeln!(
+            file,
+            "{},{},{},{}",
+            event.line_number,
+            escape_csv(&event.source_entity.name),
+            event.damage.unwrap_or(0),
+            event.heal.unwrap_or(0)
+        )?;
+    }
+    Ok(())
+}
+
+fn escape_json(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains(',') || s.contains('"') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -1,11 +1,16 @@
 pub mod app_state;
+pub mod base91;
 pub mod commands;
 pub mod encounter;
 pub mod combat_event;
+pub mod event_models;
+pub mod export;
 pub mod log_ids;
 pub mod parser;
 pub mod reader;
 pub mod repl;
+pub mod share;
+pub mod summary;
 
 pub use combat_event::*;
 pub use parser::parse_line;
@@ -1,8 +1,12 @@
+use std::path::PathBuf;
 use std::time::Instant;
 
 use clap::{Parser, Subcommand};
 
+use baras::export::{export_events, ExportFormat};
 use baras::parse_log_file;
+use baras::share::{decode_share_code, encode_share_code};
+use baras::summary::{duration_secs, print_summary, summarize_by_source};
 
 #[derive(Parser)]
 #[command(version, about = "test")]
@@ -18,6 +22,30 @@ enum Commands {
         #[arg(short, long)]
         path: String,
     },
+    /// Print a per-entity DPS/HPS/TPS summary table for a combat log
+    Summary {
+        #[arg(short, long)]
+        path: String,
+    },
+    /// Export per-event data to JSON or CSV for spreadsheet/analysis workflows
+    Export {
+        #[arg(short, long)]
+        path: String,
+        #[arg(short, long, default_value = "json")]
+        format: String,
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+    /// Produce a copy-pasteable basE91 share code for a parsed encounter
+    Share {
+        #[arg(short, long)]
+        path: String,
+    },
+    /// Decode a share code back into a summary table
+    Unshare {
+        #[arg(short, long)]
+        code: String,
+    },
 }
 
 fn main() {
@@ -34,6 +62,30 @@ fn main() {
                 println!("invalid path");
             }
         }
+        Some(Commands::Summary { path }) => {
+            let events = parse_log_file(path).expect("failed to parse log file {path}");
+            print_summary(&events);
+        }
+        Some(Commands::Export { path, format, out }) => {
+            let events = parse_log_file(path).expect("failed to parse log file {path}");
+            let format: ExportFormat = format.parse().expect("invalid --format");
+            export_events(&events, format, out).expect("failed to write export file");
+            println!("exported {} events to {}", events.len(), out.display());
+        }
+        Some(Commands::Share { path }) => {
+            let events = parse_log_file(path).expect("failed to parse log file {path}");
+            let duration = duration_secs(&events);
+            let entities = summarize_by_source(&events);
+            let code = encode_share_code(duration, &entities).expect("failed to encode share code");
+            println!("{}", code);
+        }
+        Some(Commands::Unshare { code }) => {
+            let share = decode_share_code(code).expect("invalid share code");
+            println!("Duration: {:.1}s", share.duration_secs);
+            for entity in &share.entities {
+                println!("  {:<24} damage={} heal={}", entity.name, entity.damage_total, entity.heal_total);
+            }
+        }
         None => {}
     }
 }
@@ -44,19 +44,224 @@ pub fn parse_log_file<P: AsRef<Path>>(path: P) -> std::io::Result<Vec<CombatEven
 }
 
 fn parse_line(line_number: usize, _line: &str) -> Option<CombatEvent> {
-    let (_remaining, ts) = parse_timestamp(_line)?;
-    let (_remaining, source_entity) = parse_entity(_remaining)?;
+    let (remaining, ts) = parse_timestamp(_line)?;
+    let (remaining, source_entity) = parse_entity(remaining)?;
 
-    let event = CombatEvent {
+    let mut event = CombatEvent {
         line_number,
         timestamp: ts,
         source_entity,
         ..Default::default()
     };
 
+    // Everything past the source entity is best-effort: a malformed or
+    // unrecognized tail leaves the fields parsed so far rather than
+    // dropping the event.
+    let remaining = remaining.trim_start();
+    let Some((remaining, target_entity)) = parse_entity(remaining) else {
+        return Some(event);
+    };
+
+    event.target_entity_name = non_empty(&target_entity.name);
+    event.target_entity_type = if target_entity.entity_type == EntityType::Empty {
+        None
+    } else {
+        Some(target_entity.entity_type)
+    };
+    event.target_entity_id = if target_entity.log_id != 0 {
+        Some(target_entity.log_id.to_string())
+    } else {
+        None
+    };
+    if let Some((min, max)) = target_entity.health {
+        event.target_health = Some(min as i64);
+        event.target_max_health = Some(max as i64);
+    }
+    event.target_coordinates = target_entity.coordinates;
+
+    let remaining = remaining.trim_start();
+    let Some((remaining, ability_inner)) = parse_bracket_field(remaining) else {
+        return Some(event);
+    };
+    let (action_name, action_id) = parse_name_id(ability_inner);
+    event.action_name = action_name;
+    event.action_id = action_id;
+
+    let remaining = remaining.trim_start();
+    let Some((remaining, effect_inner)) = parse_bracket_field(remaining) else {
+        return Some(event);
+    };
+    let (type_part, name_part) = match memchr(b':', effect_inner.as_bytes()) {
+        Some(colon) => (&effect_inner[..colon], &effect_inner[colon + 1..]),
+        None => (effect_inner, ""),
+    };
+    let (effect_type_name, effect_type_id) = parse_name_id(type_part);
+    let (effect_name, effect_id) = parse_name_id(name_part);
+
+    let remaining = remaining.trim_start();
+    if let Some((_remaining, value)) = parse_value(remaining) {
+        apply_value(&mut event, effect_name.as_deref(), value);
+    }
+
+    event.effect_type_name = effect_type_name;
+    event.effect_type_id = effect_type_id;
+    event.effect_id = effect_id;
+    event.effect_name = effect_name;
+
     Some(event)
 }
 
+/// `None` for an empty/whitespace-only segment, `Some` (trimmed) otherwise.
+fn non_empty(s: &str) -> Option<String> {
+    let s = s.trim();
+    if s.is_empty() {
+        None
+    } else {
+        Some(s.to_string())
+    }
+}
+
+/// Consumes a `[...]` field, returning the remaining input and the bracket's
+/// inner text.
+fn parse_bracket_field(input: &str) -> Option<(&str, &str)> {
+    let bytes = input.as_bytes();
+    if *bytes.first()? != b'[' {
+        return None;
+    }
+    let close = memchr(b']', bytes)?;
+    Some((&input[close + 1..], &input[1..close]))
+}
+
+/// Splits a `Name {id}` segment (the ability field, or either half of an
+/// effect field) into a name and id, degrading to a name-only result if the
+/// `{id}` part is missing or malformed.
+fn parse_name_id(segment: &str) -> (Option<String>, Option<String>) {
+    let segment = segment.trim();
+    match (memchr(b'{', segment.as_bytes()), memchr(b'}', segment.as_bytes())) {
+        (Some(open), Some(close)) if open < close => {
+            (non_empty(&segment[..open]), non_empty(&segment[open + 1..close]))
+        }
+        _ => (non_empty(segment), None),
+    }
+}
+
+/// Index of the `)` matching the `(` at the start of `input`. Called once for
+/// the main `(amount kind {id} ...)` value group, and again (on whatever
+/// follows it) for a sibling absorb/reduction group, e.g.
+/// `(2500 kinetic {id} *) (1200 absorbed)` - the two groups are siblings, not
+/// nested.
+fn matching_paren(input: &str) -> Option<usize> {
+    let mut depth = 0i32;
+    for (i, b) in input.bytes().enumerate() {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The parsed `(amount kind {id} ...)` value payload.
+struct ParsedValue {
+    amount: i64,
+    kind: Option<String>,
+    is_critical: bool,
+    reduced_amount: Option<String>,
+    reduction_type: Option<String>,
+    reduction_id: Option<String>,
+    threat: Option<f64>,
+}
+
+/// Consumes the value payload: `(amount kind {id} [*])` optionally followed
+/// by a sibling `(reduced reason {id})` absorb/reduction group, plus an
+/// optional trailing `<threat>`.
+fn parse_value(input: &str) -> Option<(&str, ParsedValue)> {
+    let bytes = input.as_bytes();
+    if *bytes.first()? != b'(' {
+        return None;
+    }
+    let close = matching_paren(input)?;
+    let main = &input[1..close];
+    let mut remaining = &input[close + 1..];
+
+    let is_critical = main.contains('*');
+    let mut tokens = main.split_whitespace().filter(|t| *t != "*");
+    let amount = tokens.next()?.parse::<i64>().ok()?;
+    let kind = tokens.next().and_then(non_empty);
+
+    let mut reduced_amount = None;
+    let mut reduction_type = None;
+    let mut reduction_id = None;
+    let after_main = remaining.trim_start();
+    if after_main.as_bytes().first() == Some(&b'(') {
+        if let Some(sub_close) = matching_paren(after_main) {
+            let sub = &after_main[1..sub_close];
+            let mut sub_tokens = sub.split_whitespace();
+            reduced_amount = sub_tokens.next().and_then(non_empty);
+            reduction_type = sub_tokens.next().and_then(non_empty);
+            reduction_id = sub_tokens
+                .next()
+                .and_then(|t| non_empty(t.trim_matches(|c| c == '{' || c == '}')));
+            remaining = &after_main[sub_close + 1..];
+        }
+    }
+
+    let mut threat = None;
+    let after = remaining.trim_start();
+    if after.as_bytes().first() == Some(&b'<') {
+        if let Some(end) = memchr(b'>', after.as_bytes()) {
+            threat = after[1..end].parse().ok();
+            remaining = &after[end + 1..];
+        }
+    }
+
+    Some((
+        remaining,
+        ParsedValue {
+            amount,
+            kind,
+            is_critical,
+            reduced_amount,
+            reduction_type,
+            reduction_id,
+            threat,
+        },
+    ))
+}
+
+/// Routes a parsed value payload onto the heal or damage fields depending on
+/// `effect_name`, reconstructing the pre-mitigation total from the dealt
+/// amount plus whatever the absorb/shield sub-clause reported reduced.
+fn apply_value(event: &mut CombatEvent, effect_name: Option<&str>, value: ParsedValue) {
+    event.is_critical = Some(value.is_critical);
+    event.threat = value.threat;
+    event.damage_type_id = value.kind;
+    event.damage_reduced = value.reduced_amount;
+    event.reduction_type_id = value.reduction_type;
+    event.reduction_class_id = value.reduction_id;
+
+    let reduced: i64 = event
+        .damage_reduced
+        .as_deref()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+
+    let is_heal = effect_name.is_some_and(|n| n.eq_ignore_ascii_case("heal"));
+    if is_heal {
+        event.effective_heal = Some(value.amount);
+        event.heal = Some(value.amount + reduced);
+    } else {
+        event.effective_damage = Some(value.amount);
+        event.damage = Some(value.amount + reduced);
+    }
+}
+
 pub fn parse_timestamp(input: &str) -> Option<(&str, Timestamp)> {
     let b = input.as_bytes();
     if b.len() < 14 || b[0] != b'[' || b[3] != b':' || b[6] != b':' || b[9] != b'.' || b[13] != b']'
@@ -99,7 +304,7 @@ pub fn parse_entity(input: &str) -> Option<(&str, Entity)> {
 
     if bytes[1] == b'=' {
         return Some((
-            &input[1..],
+            &input[delim_pos + 1..],
             Entity {
                 ..Default::default()
             },
@@ -110,19 +315,49 @@ pub fn parse_entity(input: &str) -> Option<(&str, Entity)> {
     let name_segment = &input[2..first_pipe];
 
     let (name, class_id, log_id, entity_type) = parse_entity_name_id(name_segment)?;
+    let (coordinates, health) = parse_entity_position(&input[first_pipe + 1..delim_pos]);
 
     Some((
-        &input[first_pipe..],
+        &input[delim_pos + 1..],
         Entity {
             name: name.to_string(),
             class_id,
             log_id,
             entity_type,
-            ..Default::default()
+            coordinates,
+            health,
         },
     ))
 }
 
+/// Parse the `|(x,y,z,heading)|(cur/max)` segment between an entity's name
+/// and the closing `]`, e.g. `(137.28,-120.98,-8.85,81.28)|(0/19129210)`.
+/// Either piece (or both) may be absent, so each `|`-separated part is
+/// classified independently rather than assumed to be in a fixed position.
+fn parse_entity_position(segment: &str) -> (Option<String>, Option<(i32, i32)>) {
+    let mut coordinates = None;
+    let mut health = None;
+
+    for part in segment.split('|') {
+        let part = part.trim();
+        let inner = part.strip_prefix('(').and_then(|s| s.strip_suffix(')')).unwrap_or(part);
+        if inner.is_empty() {
+            continue;
+        }
+
+        if let Some((cur, max)) = inner.split_once('/') {
+            if let (Ok(cur), Ok(max)) = (cur.trim().parse::<i32>(), max.trim().parse::<i32>()) {
+                health = Some((cur, max));
+                continue;
+            }
+        }
+
+        coordinates = Some(inner.to_string());
+    }
+
+    (coordinates, health)
+}
+
 pub fn parse_entity_name_id(input: &str) -> Option<(&str, i64, i64, EntityType)> {
     let bytes = input.as_bytes();
 
@@ -160,3 +395,71 @@ pub fn parse_entity_name_id(input: &str) -> Option<(&str, i64, i64, EntityType)>
 
     Some((npc_name, npc_char_id, npc_log_id, EntityType::Npc))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_value_without_absorb() {
+        let (remaining, value) = parse_value("(2500 kinetic {836045448945664} *) <150.5>").unwrap();
+        assert_eq!(value.amount, 2500);
+        assert_eq!(value.kind.as_deref(), Some("kinetic"));
+        assert!(value.is_critical);
+        assert_eq!(value.reduced_amount, None);
+        assert_eq!(value.reduction_type, None);
+        assert_eq!(value.reduction_id, None);
+        assert_eq!(value.threat, Some(150.5));
+        assert_eq!(remaining, "");
+    }
+
+    #[test]
+    fn parse_value_with_sibling_absorb() {
+        // The absorb/reduction clause is a sibling `(...)` group following the
+        // main value group, not nested inside it.
+        let (remaining, value) =
+            parse_value("(1985 kinetic {836045448945664}) (1200 absorbed {836045448945665}) <50>")
+                .unwrap();
+        assert_eq!(value.amount, 1985);
+        assert_eq!(value.kind.as_deref(), Some("kinetic"));
+        assert_eq!(value.reduced_amount.as_deref(), Some("1200"));
+        assert_eq!(value.reduction_type.as_deref(), Some("absorbed"));
+        assert_eq!(value.reduction_id.as_deref(), Some("836045448945665"));
+        assert_eq!(value.threat, Some(50.0));
+        assert_eq!(remaining, "");
+    }
+
+    #[test]
+    fn apply_value_reconstructs_pre_mitigation_damage_with_absorb() {
+        let mut event = CombatEvent::default();
+        let (_, value) =
+            parse_value("(1985 kinetic {836045448945664}) (1200 absorbed {836045448945665})").unwrap();
+        apply_value(&mut event, Some("Apply Effect"), value);
+
+        assert_eq!(event.effective_damage, Some(1985));
+        // Pre-mitigation total is the dealt amount plus whatever was absorbed.
+        assert_eq!(event.damage, Some(3185));
+        assert_eq!(event.reduction_type_id.as_deref(), Some("absorbed"));
+        assert_eq!(event.reduction_class_id.as_deref(), Some("836045448945665"));
+    }
+
+    #[test]
+    fn parse_line_with_real_absorb_log_line() {
+        let line = "[20:15:30.123] [@Jerran Zeva#689501114780828|(-4700.43,-4750.48,710.03,-0.71)|(1/414851)] [Dread Master Bestia {3273941900591104}:5320000112163|(137.28,-120.98,-8.85,81.28)|(0/19129210)] [Saber Throw {836045448945111}] [Damage {836045448945222}:Flesh Wound {836045448945333}] (1985 kinetic {836045448945664}) (1200 absorbed {836045448945665}) <75.25>";
+
+        let event = parse_line(1, line).expect("line should parse");
+
+        assert_eq!(event.effective_damage, Some(1985));
+        assert_eq!(event.damage, Some(3185));
+        assert_eq!(event.damage_reduced.as_deref(), Some("1200"));
+        assert_eq!(event.reduction_type_id.as_deref(), Some("absorbed"));
+        assert_eq!(event.reduction_class_id.as_deref(), Some("836045448945665"));
+        assert_eq!(event.threat, Some(75.25));
+
+        assert_eq!(event.target_coordinates.as_deref(), Some("137.28,-120.98,-8.85,81.28"));
+        assert_eq!(event.target_health, Some(0));
+        assert_eq!(event.target_max_health, Some(19129210));
+        assert_eq!(event.source_entity.coordinates.as_deref(), Some("-4700.43,-4750.48,710.03,-0.71"));
+        assert_eq!(event.source_entity.health, Some((1, 414851)));
+    }
+}
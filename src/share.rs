@@ -0,0 +1,111 @@
+//! Shareable compact encounter codes
+//!
+//! Serializes a per-entity encounter summary to bytes, gzip-compresses it,
+//! and text-encodes the result with [`base91`] so it can be pasted as a
+//! single ASCII string into Discord/forums and reconstructed by another
+//! baras user without hosting any files.
+
+use std::io::{Read, Write};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+use crate::base91;
+use crate::summary::EntitySummary;
+
+/// Everything needed to reconstruct an encounter summary from a share code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareCode {
+    pub duration_secs: f64,
+    pub entities: Vec<SharedEntity>,
+}
+
+/// A compact, serde-friendly stand-in for [`EntitySummary`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedEntity {
+    pub name: String,
+    pub damage_total: i64,
+    pub effective_damage_total: i64,
+    pub heal_total: i64,
+    pub effective_heal_total: i64,
+    pub threat_total: f64,
+}
+
+impl From<&EntitySummary> for SharedEntity {
+    fn from(e: &EntitySummary) -> Self {
+        Self {
+            name: e.name.clone(),
+            damage_total: e.damage_total,
+            effective_damage_total: e.effective_damage_total,
+            heal_total: e.heal_total,
+            effective_heal_total: e.effective_heal_total,
+            threat_total: e.threat_total,
+        }
+    }
+}
+
+/// Encode an encounter summary as a copy-pasteable basE91 share code.
+pub fn encode_share_code(duration_secs: f64, entities: &[EntitySummary]) -> Result<String, String> {
+    let payload = ShareCode {
+        duration_secs,
+        entities: entities.iter().map(SharedEntity::from).collect(),
+    };
+
+    let json = serde_json::to_vec(&payload).map_err(|e| e.to_string())?;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&json).map_err(|e| e.to_string())?;
+    let compressed = encoder.finish().map_err(|e| e.to_string())?;
+
+    Ok(base91::encode(&compressed))
+}
+
+/// Decode a basE91 share code back into an encounter summary.
+pub fn decode_share_code(code: &str) -> Result<ShareCode, String> {
+    let compressed = base91::decode(code).ok_or_else(|| "invalid share code".to_string())?;
+
+    let mut decoder = GzDecoder::new(compressed.as_slice());
+    let mut json = Vec::new();
+    decoder
+        .read_to_end(&mut json)
+        .map_err(|e| format!("failed to decompress share code: {e}"))?;
+
+    serde_json::from_slice(&json).map_err(|e| format!("failed to parse share code: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn share_code_round_trips_entity_totals() {
+        let entities = vec![EntitySummary {
+            name: "Jerran Zeva".to_string(),
+            damage_total: 1_250_000,
+            effective_damage_total: 1_100_000,
+            heal_total: 500_000,
+            effective_heal_total: 420_000,
+            threat_total: 88_000.0,
+        }];
+
+        let code = encode_share_code(300.0, &entities).expect("encode should succeed");
+        let decoded = decode_share_code(&code).expect("decode should succeed");
+
+        assert_eq!(decoded.duration_secs, 300.0);
+        assert_eq!(decoded.entities.len(), 1);
+        let entity = &decoded.entities[0];
+        assert_eq!(entity.name, "Jerran Zeva");
+        assert_eq!(entity.damage_total, 1_250_000);
+        assert_eq!(entity.effective_damage_total, 1_100_000);
+        assert_eq!(entity.heal_total, 500_000);
+        assert_eq!(entity.effective_heal_total, 420_000);
+        assert_eq!(entity.threat_total, 88_000.0);
+    }
+
+    #[test]
+    fn decode_share_rejects_garbage() {
+        assert!(decode_share_code("not a real share code").is_err());
+    }
+}
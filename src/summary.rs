@@ -0,0 +1,110 @@
+//! Per-encounter DPS/HPS/TPS summary tables
+//!
+//! Aggregates parsed [`CombatEvent`]s by source entity so the `summary`
+//! CLI subcommand can print a quick table without spinning up the overlay UI.
+
+use crate::event_models::CombatEvent;
+use std::collections::BTreeMap;
+
+/// Aggregated totals for a single source entity across a parsed log.
+#[derive(Debug, Clone, Default)]
+pub struct EntitySummary {
+    pub name: String,
+    pub damage_total: i64,
+    pub effective_damage_total: i64,
+    pub heal_total: i64,
+    pub effective_heal_total: i64,
+    pub threat_total: f64,
+}
+
+impl EntitySummary {
+    fn rate(total: i64, duration_secs: f64) -> f64 {
+        if duration_secs <= 0.0 {
+            0.0
+        } else {
+            total as f64 / duration_secs
+        }
+    }
+
+    pub fn dps(&self, duration_secs: f64) -> f64 {
+        Self::rate(self.damage_total, duration_secs)
+    }
+
+    pub fn hps(&self, duration_secs: f64) -> f64 {
+        Self::rate(self.heal_total, duration_secs)
+    }
+
+    pub fn tps(&self, duration_secs: f64) -> f64 {
+        if duration_secs <= 0.0 {
+            0.0
+        } else {
+            self.threat_total / duration_secs
+        }
+    }
+}
+
+/// Encounter duration in seconds, derived from the first and last
+/// timestamps seen in the event slice.
+pub fn duration_secs(events: &[CombatEvent]) -> f64 {
+    let (Some(first), Some(last)) = (events.first(), events.last()) else {
+        return 0.0;
+    };
+
+    let to_millis = |t: &crate::event_models::Timestamp| -> i64 {
+        (t.hour as i64 * 3600 + t.minute as i64 * 60 + t.second as i64) * 1000 + t.millis as i64
+    };
+
+    let delta = to_millis(&last.timestamp) - to_millis(&first.timestamp);
+    (delta.max(0) as f64) / 1000.0
+}
+
+/// Aggregate per-source totals, skipping events with no named source (e.g.
+/// environment ticks that weren't attributed to an entity).
+pub fn summarize_by_source(events: &[CombatEvent]) -> Vec<EntitySummary> {
+    let mut totals: BTreeMap<String, EntitySummary> = BTreeMap::new();
+
+    for event in events {
+        let name = &event.source_entity.name;
+        if name.is_empty() {
+            continue;
+        }
+
+        let entry = totals.entry(name.clone()).or_insert_with(|| EntitySummary {
+            name: name.clone(),
+            ..Default::default()
+        });
+
+        entry.damage_total += event.damage.unwrap_or(0);
+        entry.effective_damage_total += event.effective_damage.unwrap_or(0);
+        entry.heal_total += event.heal.unwrap_or(0);
+        entry.effective_heal_total += event.effective_heal.unwrap_or(0);
+        entry.threat_total += event.threat.unwrap_or(0.0);
+    }
+
+    let mut rows: Vec<_> = totals.into_values().collect();
+    rows.sort_by(|a, b| b.damage_total.cmp(&a.damage_total));
+    rows
+}
+
+/// Print a per-entity DPS/HPS/TPS table to stdout.
+pub fn print_summary(events: &[CombatEvent]) {
+    let duration = duration_secs(events);
+    let rows = summarize_by_source(events);
+
+    println!(
+        "{:<24} {:>12} {:>10} {:>12} {:>10} {:>10}",
+        "Name", "Damage", "DPS", "Healing", "HPS", "TPS"
+    );
+    for row in &rows {
+        println!(
+            "{:<24} {:>12} {:>10.1} {:>12} {:>10.1} {:>10.1}",
+            row.name,
+            row.damage_total,
+            row.dps(duration),
+            row.heal_total,
+            row.hps(duration),
+            row.tps(duration)
+        );
+    }
+    println!("\nDuration: {:.1}s, {} entities", duration, rows.len());
+}